@@ -0,0 +1,398 @@
+#[cfg(feature = "arrow2_ih")]
+extern crate arrow2_ih as arrow2;
+
+use crate::df::{DataFrame, Series};
+use crate::Error;
+use arrow2::array::{Array, BooleanArray, PrimitiveArray, Utf8Array};
+use arrow2::datatypes::{DataType, Field, Schema};
+use chrono::DateTime;
+use std::io::{BufWriter, Write};
+
+/// CSV output options
+#[derive(Debug, Clone)]
+pub struct CsvWriteOptions {
+    pub delimiter: char,
+    pub quote: char,
+    pub header: bool,
+}
+
+impl Default for CsvWriteOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            quote: '"',
+            header: true,
+        }
+    }
+}
+
+impl CsvWriteOptions {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// CSV input options
+#[derive(Debug, Clone)]
+pub struct CsvReadOptions {
+    pub delimiter: char,
+    pub quote: char,
+    pub has_header: bool,
+    /// explicit column types, matched by position, header names are ignored if set
+    pub schema: Option<Schema>,
+    /// number of rows scanned to infer column types if no schema is set
+    pub infer_rows: usize,
+}
+
+impl Default for CsvReadOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            quote: '"',
+            has_header: true,
+            schema: None,
+            infer_rows: 100,
+        }
+    }
+}
+
+impl CsvReadOptions {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl DataFrame {
+    /// Parse a data frame from CSV bytes
+    ///
+    /// If no schema is provided, column types are inferred: Int64, Float64 or LargeUtf8 as the
+    /// fallback. Empty fields and the values which can not be parsed are set to nulls. Columns
+    /// are named "col_N" if there is no header.
+    pub fn from_csv(bytes: &[u8], options: CsvReadOptions) -> Result<Self, Error> {
+        let src = std::str::from_utf8(bytes).map_err(Error::other)?;
+        let mut records = parse_records(src, options.delimiter, options.quote);
+        let header = if options.has_header && !records.is_empty() {
+            Some(records.remove(0))
+        } else {
+            None
+        };
+        let cols = if let Some(ref schema) = options.schema {
+            schema.fields.len()
+        } else if let Some(ref h) = header {
+            h.len()
+        } else if let Some(first) = records.first() {
+            first.len()
+        } else {
+            return Ok(DataFrame::new0());
+        };
+        let mut fields = Vec::with_capacity(cols);
+        let mut data: Vec<Series> = Vec::with_capacity(cols);
+        for i in 0..cols {
+            let values: Vec<Option<&str>> = records
+                .iter()
+                .map(|r| r.get(i).and_then(Option::as_deref))
+                .collect();
+            let field = if let Some(ref schema) = options.schema {
+                schema.fields[i].clone()
+            } else {
+                let name = header
+                    .as_ref()
+                    .and_then(|h| h.get(i).cloned().flatten())
+                    .unwrap_or_else(|| format!("col_{}", i));
+                Field::new(name, infer_type(&values, options.infer_rows), true)
+            };
+            data.push(parse_series(&values, &field.data_type)?);
+            fields.push(field);
+        }
+        DataFrame::from_parts(fields, data, options.schema.map(|schema| schema.metadata))
+    }
+    /// Serialize the data frame to CSV bytes with the default options
+    #[inline]
+    pub fn to_csv(&self) -> Result<Vec<u8>, Error> {
+        self.to_csv_with_options(&CsvWriteOptions::default())
+    }
+    /// Serialize the data frame to CSV bytes
+    ///
+    /// Floats and integers are written as-is, timestamps as RFC3339 (UTC), nulls as empty fields
+    pub fn to_csv_with_options(&self, options: &CsvWriteOptions) -> Result<Vec<u8>, Error> {
+        let mut writer = BufWriter::new(Vec::new());
+        let mut buf = [0; 4];
+        let delimiter = options.delimiter.encode_utf8(&mut buf).as_bytes();
+        if options.header {
+            for (i, name) in self.names().into_iter().enumerate() {
+                if i > 0 {
+                    writer.write_all(delimiter)?;
+                }
+                write_str(&mut writer, name, Some(options))?;
+            }
+            writer.write_all(b"\n")?;
+        }
+        for row in 0..self.rows().unwrap_or_default() {
+            for (i, (serie, field)) in self.data().iter().zip(self.fields()).enumerate() {
+                if i > 0 {
+                    writer.write_all(delimiter)?;
+                }
+                write_value(
+                    &mut writer,
+                    serie.as_ref(),
+                    &field.data_type,
+                    row,
+                    Some(options),
+                )?;
+            }
+            writer.write_all(b"\n")?;
+        }
+        writer.into_inner().map_err(|e| Error::Io(e.into_error()))
+    }
+}
+
+fn write_str<W: Write>(w: &mut W, s: &str, options: Option<&CsvWriteOptions>) -> Result<(), Error> {
+    if let Some(opts) = options {
+        if s.contains(|c: char| c == opts.delimiter || c == opts.quote || c == '\n' || c == '\r') {
+            let mut buf = [0; 4];
+            let quote = opts.quote.encode_utf8(&mut buf);
+            let escaped = s.replace(&*quote, &quote.repeat(2));
+            write!(w, "{}{}{}", quote, escaped, quote)?;
+            return Ok(());
+        }
+    }
+    w.write_all(s.as_bytes())?;
+    Ok(())
+}
+
+/// Write a single value formatted according to the data type, nothing is written for nulls
+///
+/// If CSV options are provided, strings are quoted when required
+pub(crate) fn write_value<W: Write>(
+    w: &mut W,
+    serie: &dyn Array,
+    data_type: &DataType,
+    index: usize,
+    options: Option<&CsvWriteOptions>,
+) -> Result<(), Error> {
+    if serie.is_null(index) {
+        return Ok(());
+    }
+    macro_rules! arr {
+        ($kind: ty) => {
+            serie
+                .as_any()
+                .downcast_ref::<$kind>()
+                .ok_or(Error::TypeMismatch)?
+                .value(index)
+        };
+    }
+    match data_type {
+        DataType::Boolean => write!(w, "{}", arr!(BooleanArray))?,
+        DataType::Float32 => write!(w, "{}", arr!(PrimitiveArray<f32>))?,
+        DataType::Float64 => write!(w, "{}", arr!(PrimitiveArray<f64>))?,
+        DataType::Int8 => write!(w, "{}", arr!(PrimitiveArray<i8>))?,
+        DataType::Int16 => write!(w, "{}", arr!(PrimitiveArray<i16>))?,
+        DataType::Int32 => write!(w, "{}", arr!(PrimitiveArray<i32>))?,
+        DataType::Int64 => write!(w, "{}", arr!(PrimitiveArray<i64>))?,
+        DataType::UInt8 => write!(w, "{}", arr!(PrimitiveArray<u8>))?,
+        DataType::UInt16 => write!(w, "{}", arr!(PrimitiveArray<u16>))?,
+        DataType::UInt32 => write!(w, "{}", arr!(PrimitiveArray<u32>))?,
+        DataType::UInt64 => write!(w, "{}", arr!(PrimitiveArray<u64>))?,
+        DataType::Utf8 => write_str(w, arr!(Utf8Array<i32>), options)?,
+        DataType::LargeUtf8 => write_str(w, arr!(Utf8Array<i64>), options)?,
+        DataType::Timestamp(time_unit, _) => write!(
+            w,
+            "{}",
+            crate::timestamp_rfc3339(arr!(PrimitiveArray<i64>), time_unit)?
+        )?,
+        v => {
+            return Err(Error::Unimplemented(format!("{:?}", v)));
+        }
+    }
+    Ok(())
+}
+
+fn take_field(field: &mut String, quoted: &mut bool) -> Option<String> {
+    if field.is_empty() && !std::mem::replace(quoted, false) {
+        None
+    } else {
+        *quoted = false;
+        Some(std::mem::take(field))
+    }
+}
+
+fn parse_records(src: &str, delimiter: char, quote: char) -> Vec<Vec<Option<String>>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut quoted = false;
+    let mut chars = src.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == quote {
+                if chars.peek() == Some(&quote) {
+                    field.push(quote);
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+        } else if c == quote {
+            in_quotes = true;
+            quoted = true;
+        } else if c == delimiter {
+            record.push(take_field(&mut field, &mut quoted));
+        } else if c == '\n' || c == '\r' {
+            if c == '\r' && chars.peek() == Some(&'\n') {
+                chars.next();
+            }
+            record.push(take_field(&mut field, &mut quoted));
+            records.push(std::mem::take(&mut record));
+        } else {
+            field.push(c);
+        }
+    }
+    if !field.is_empty() || quoted || !record.is_empty() {
+        record.push(take_field(&mut field, &mut quoted));
+        records.push(record);
+    }
+    records
+}
+
+fn infer_type(values: &[Option<&str>], rows: usize) -> DataType {
+    let mut sample = values.iter().take(rows).flatten().peekable();
+    if sample.peek().is_none() {
+        DataType::LargeUtf8
+    } else if sample.clone().all(|s| s.parse::<i64>().is_ok()) {
+        DataType::Int64
+    } else if sample.all(|s| s.parse::<f64>().is_ok()) {
+        DataType::Float64
+    } else {
+        DataType::LargeUtf8
+    }
+}
+
+/// Parse string values into a series of the given data type, unparsable values are set to nulls
+///
+/// Timestamps are parsed from RFC3339 strings
+pub(crate) fn parse_series(values: &[Option<&str>], data_type: &DataType) -> Result<Series, Error> {
+    macro_rules! parse_vec {
+        ($kind: ty) => {
+            values
+                .iter()
+                .map(|v| v.and_then(|s| s.parse::<$kind>().ok()))
+                .collect::<Vec<Option<$kind>>>()
+        };
+    }
+    macro_rules! prim {
+        ($kind: ty) => {
+            PrimitiveArray::<$kind>::from(parse_vec!($kind)).boxed()
+        };
+    }
+    let series = match data_type {
+        DataType::Boolean => BooleanArray::from(parse_vec!(bool)).boxed(),
+        DataType::Float32 => prim!(f32),
+        DataType::Float64 => prim!(f64),
+        DataType::Int8 => prim!(i8),
+        DataType::Int16 => prim!(i16),
+        DataType::Int32 => prim!(i32),
+        DataType::Int64 => prim!(i64),
+        DataType::UInt8 => prim!(u8),
+        DataType::UInt16 => prim!(u16),
+        DataType::UInt32 => prim!(u32),
+        DataType::UInt64 => prim!(u64),
+        DataType::Utf8 => Utf8Array::<i32>::from(values).boxed(),
+        DataType::LargeUtf8 => Utf8Array::<i64>::from(values).boxed(),
+        DataType::Timestamp(time_unit, _) => {
+            let ts: Vec<Option<i64>> = values
+                .iter()
+                .map(|v| {
+                    v.and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+                        .and_then(|dt| crate::naive_to_timestamp(&dt.naive_utc(), time_unit))
+                })
+                .collect();
+            PrimitiveArray::<i64>::from(ts)
+                .to(data_type.clone())
+                .boxed()
+        }
+        v => {
+            return Err(Error::Unimplemented(format!("{:?}", v)));
+        }
+    };
+    Ok(series)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_csv() {
+        let df = DataFrame::from_csv(b"a,b,c\n1,1.5,x\n2,oops,\n", CsvReadOptions::new()).unwrap();
+        assert_eq!(df.names(), vec!["a", "b", "c"]);
+        assert_eq!(df.rows(), Some(2));
+        let types: Vec<&DataType> = df.fields().iter().map(|f| &f.data_type).collect();
+        assert_eq!(
+            types,
+            vec![&DataType::Int64, &DataType::Float64, &DataType::LargeUtf8]
+        );
+        assert_eq!(df.get_int64("a").unwrap().values().as_slice(), &[1, 2]);
+        let b = df.get_float64("b").unwrap();
+        assert_eq!(b.value(0), 1.5);
+        assert!(b.is_null(1));
+        assert_eq!(df.null_count("c").unwrap(), 1);
+    }
+    #[test]
+    fn test_from_csv_infer_rows() {
+        let mut options = CsvReadOptions::new();
+        options.infer_rows = 1;
+        let df = DataFrame::from_csv(b"a\n1\n2.5\n", options).unwrap();
+        assert_eq!(df.fields()[0].data_type, DataType::Int64);
+        assert_eq!(df.null_count("a").unwrap(), 1);
+    }
+    #[test]
+    fn test_from_csv_schema() {
+        let mut options = CsvReadOptions::new();
+        options.schema = Some(Schema::from(vec![
+            Field::new("x", DataType::Float64, true),
+            Field::new("y", DataType::Utf8, true),
+        ]));
+        options.delimiter = ';';
+        let df = DataFrame::from_csv(b"a;b\n1;\"q;\"\"z\"\"\"\n", options).unwrap();
+        assert_eq!(df.names(), vec!["x", "y"]);
+        assert_eq!(df.get_float64("x").unwrap().value(0), 1.0);
+        assert_eq!(
+            df.get_utf8_iter("y").unwrap().collect::<Vec<_>>(),
+            vec![Some("q;\"z\"")]
+        );
+    }
+    #[test]
+    fn test_from_csv_edge_cases() {
+        let df = DataFrame::from_csv(b"", CsvReadOptions::new()).unwrap();
+        assert_eq!(df.rows(), None);
+        assert!(df.names().is_empty());
+        let df = DataFrame::from_csv(b"a,b\n", CsvReadOptions::new()).unwrap();
+        assert_eq!(df.names(), vec!["a", "b"]);
+        assert_eq!(df.rows(), Some(0));
+        let df = DataFrame::from_csv(b"a,b", CsvReadOptions::new()).unwrap();
+        assert_eq!(df.names(), vec!["a", "b"]);
+        assert_eq!(df.rows(), Some(0));
+        let trailing = DataFrame::from_csv(b"a\n1\n2\n", CsvReadOptions::new()).unwrap();
+        let no_trailing = DataFrame::from_csv(b"a\n1\n2", CsvReadOptions::new()).unwrap();
+        assert_eq!(trailing.rows(), Some(2));
+        assert!(trailing == no_trailing);
+        let mut options = CsvReadOptions::new();
+        options.has_header = false;
+        let df = DataFrame::from_csv(b"1,2\r\n3,4\r\n", options).unwrap();
+        assert_eq!(df.names(), vec!["col_0", "col_1"]);
+        assert_eq!(df.rows(), Some(2));
+    }
+    #[test]
+    fn test_to_csv_round_trip() {
+        let df = DataFrame::from_csv(b"a,b\n1,\"x,y\"\n,z\n", CsvReadOptions::new()).unwrap();
+        assert_eq!(df.to_csv().unwrap(), b"a,b\n1,\"x,y\"\n,z\n");
+        let parsed = DataFrame::from_csv(&df.to_csv().unwrap(), CsvReadOptions::new()).unwrap();
+        assert!(parsed == df);
+    }
+}
@@ -1,10 +1,10 @@
 #[cfg(feature = "arrow2_ih")]
 extern crate arrow2_ih as arrow2;
 
-use crate::df::DataFrame;
+use crate::df::{DataFrame, Series};
 use crate::Error;
 use arrow2::array::{BooleanArray, PrimitiveArray, Utf8Array};
-use arrow2::datatypes::DataType;
+use arrow2::datatypes::{DataType, Field, Schema};
 use serde::Deserialize;
 use serde_json::Value;
 
@@ -22,6 +22,143 @@ impl TryFrom<&DataFrame> for Value {
     }
 }
 
+impl DataFrame {
+    /// Parse a data frame from JSON array of row objects
+    ///
+    /// If no schema is provided, column names and types are inferred from the first object.
+    /// Missing keys and the values which can not be coerced to the column type are set to nulls.
+    pub fn from_json(bytes: &[u8], schema: Option<&Schema>) -> Result<Self, Error> {
+        let value: Value =
+            serde_json::from_slice(bytes).map_err(|e| Error::ParseError(e.to_string()))?;
+        let rows = match value {
+            Value::Array(rows) => rows,
+            _ => return Err(Error::ParseError("JSON array expected".to_owned())),
+        };
+        let mut objects = Vec::with_capacity(rows.len());
+        for row in &rows {
+            match row {
+                Value::Object(map) => objects.push(map),
+                _ => return Err(Error::ParseError("JSON object expected".to_owned())),
+            }
+        }
+        let fields: Vec<Field> = if let Some(s) = schema {
+            s.fields.clone()
+        } else if let Some(first) = objects.first() {
+            first
+                .iter()
+                .map(|(name, val)| Field::new(name, infer_type(val), true))
+                .collect()
+        } else {
+            return Ok(DataFrame::new0());
+        };
+        let mut data = Vec::with_capacity(fields.len());
+        for field in &fields {
+            let values: Vec<Option<&Value>> =
+                objects.iter().map(|map| map.get(&field.name)).collect();
+            data.push(values_to_series(&values, &field.data_type)?);
+        }
+        DataFrame::from_parts(fields, data, schema.map(|s| s.metadata.clone()))
+    }
+}
+
+fn infer_type(value: &Value) -> DataType {
+    match value {
+        Value::Bool(_) => DataType::Boolean,
+        Value::Number(n) if n.is_i64() => DataType::Int64,
+        Value::Number(_) => DataType::Float64,
+        _ => DataType::LargeUtf8,
+    }
+}
+
+fn value_to_i64(value: &Value) -> Option<i64> {
+    match value {
+        Value::Number(n) => n.as_i64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn value_to_u64(value: &Value) -> Option<u64> {
+    match value {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn value_to_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn value_to_bool(value: &Value) -> Option<bool> {
+    match value {
+        Value::Bool(b) => Some(*b),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn value_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        v => Some(v.to_string()),
+    }
+}
+
+fn values_to_series(values: &[Option<&Value>], data_type: &DataType) -> Result<Series, Error> {
+    macro_rules! conv {
+        ($func: expr) => {
+            values
+                .iter()
+                .map(|v| v.and_then($func))
+                .collect::<Vec<Option<_>>>()
+        };
+    }
+    macro_rules! prim {
+        ($kind: ty, $func: expr) => {
+            PrimitiveArray::<$kind>::from(conv!(
+                |v| $func(v).and_then(|n| <$kind>::try_from(n).ok())
+            ))
+            .boxed()
+        };
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    let series = match data_type {
+        DataType::Boolean => BooleanArray::from(conv!(value_to_bool)).boxed(),
+        DataType::Float32 => {
+            PrimitiveArray::<f32>::from(conv!(|v| value_to_f64(v).map(|n| n as f32))).boxed()
+        }
+        DataType::Float64 => PrimitiveArray::<f64>::from(conv!(value_to_f64)).boxed(),
+        DataType::Int8 => prim!(i8, value_to_i64),
+        DataType::Int16 => prim!(i16, value_to_i64),
+        DataType::Int32 => prim!(i32, value_to_i64),
+        DataType::Int64 => PrimitiveArray::<i64>::from(conv!(value_to_i64)).boxed(),
+        DataType::UInt8 => prim!(u8, value_to_u64),
+        DataType::UInt16 => prim!(u16, value_to_u64),
+        DataType::UInt32 => prim!(u32, value_to_u64),
+        DataType::UInt64 => PrimitiveArray::<u64>::from(conv!(value_to_u64)).boxed(),
+        DataType::Utf8 => Utf8Array::<i32>::from(conv!(value_to_string)).boxed(),
+        DataType::LargeUtf8 => Utf8Array::<i64>::from(conv!(value_to_string)).boxed(),
+        DataType::Timestamp(time_unit, _) => PrimitiveArray::<i64>::from(conv!(|v| match v {
+            Value::String(s) => chrono::DateTime::parse_from_rfc3339(s)
+                .ok()
+                .and_then(|dt| crate::naive_to_timestamp(&dt.naive_utc(), time_unit)),
+            v => value_to_i64(v),
+        }))
+        .to(data_type.clone())
+        .boxed(),
+        v => {
+            return Err(Error::Unimplemented(format!("{:?}", v)));
+        }
+    };
+    Ok(series)
+}
+
 #[derive(Default)]
 pub struct Parser {
     type_map: Vec<(String, DataType)>,
@@ -113,3 +250,98 @@ impl Parser {
         Ok(df)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_json() {
+        let compact = br#"[{"a":1,"b":1.5,"c":"x","d":true},{"a":2,"c":null,"d":false}]"#;
+        let pretty = br#"[
+  {
+    "a": 1,
+    "b": 1.5,
+    "c": "x",
+    "d": true
+  },
+  {
+    "a": 2,
+    "c": null,
+    "d": false
+  }
+]"#;
+        let df = DataFrame::from_json(compact, None).unwrap();
+        assert!(df == DataFrame::from_json(pretty, None).unwrap());
+        assert_eq!(df.rows(), Some(2));
+        let types: Vec<&DataType> = df.fields().iter().map(|f| &f.data_type).collect();
+        assert_eq!(
+            types,
+            vec![
+                &DataType::Int64,
+                &DataType::Float64,
+                &DataType::LargeUtf8,
+                &DataType::Boolean
+            ]
+        );
+        assert_eq!(df.get_int64("a").unwrap().values().as_slice(), &[1, 2]);
+        assert!(df.get_float64("b").unwrap().is_null(1));
+        assert_eq!(df.null_count("c").unwrap(), 1);
+        assert_eq!(df.get_bool("d").unwrap().get(1), Some(false));
+    }
+    #[test]
+    fn test_from_json_mixed_types() {
+        let df = DataFrame::from_json(
+            br#"[{"a":1},{"a":"2"},{"a":2.5},{"a":"x"},{"a":[1]}]"#,
+            None,
+        )
+        .unwrap();
+        let a = df.get_int64("a").unwrap();
+        assert_eq!(
+            a.iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(1), Some(2), None, None, None]
+        );
+        let df = DataFrame::from_json(br#"[{"a":1.5},{"a":2}]"#, None).unwrap();
+        assert_eq!(
+            df.get_float64("a").unwrap().values().as_slice(),
+            &[1.5, 2.0]
+        );
+        let df = DataFrame::from_json(br#"[{"a":"x"},{"a":2},{"a":true}]"#, None).unwrap();
+        assert_eq!(
+            df.get_utf8_iter("a").unwrap().collect::<Vec<_>>(),
+            vec![Some("x"), Some("2"), Some("true")]
+        );
+    }
+    #[test]
+    fn test_from_json_schema() {
+        let schema = Schema::from(vec![
+            Field::new("b", DataType::Float64, true),
+            Field::new("a", DataType::Int32, true),
+            Field::new("z", DataType::Utf8, true),
+        ]);
+        let df = DataFrame::from_json(br#"[{"a":1,"b":2},{"a":3}]"#, Some(&schema)).unwrap();
+        assert_eq!(df.names(), vec!["b", "a", "z"]);
+        let b = df.get_float64("b").unwrap();
+        assert_eq!(b.value(0), 2.0);
+        assert!(b.is_null(1));
+        assert_eq!(df.null_count("z").unwrap(), 2);
+        assert_eq!(df.fields()[1].data_type, DataType::Int32);
+    }
+    #[test]
+    fn test_from_json_errors() {
+        assert!(matches!(
+            DataFrame::from_json(b"[{", None),
+            Err(Error::ParseError(_))
+        ));
+        assert!(matches!(
+            DataFrame::from_json(br#"{"a":1}"#, None),
+            Err(Error::ParseError(_))
+        ));
+        assert!(matches!(
+            DataFrame::from_json(b"[1]", None),
+            Err(Error::ParseError(_))
+        ));
+        let df = DataFrame::from_json(b"[]", None).unwrap();
+        assert_eq!(df.rows(), None);
+    }
+}
@@ -0,0 +1,290 @@
+#[cfg(feature = "arrow2_ih")]
+extern crate arrow2_ih as arrow2;
+
+use crate::df::DataFrame;
+use crate::Error;
+use arrow2::array::{Array, BooleanArray, PrimitiveArray, Utf8Array};
+use arrow2::datatypes::DataType;
+#[cfg(feature = "serde_json")]
+use serde::ser::{Error as _, Serialize, SerializeMap, SerializeSeq, Serializer};
+#[cfg(not(feature = "serde_json"))]
+use std::io::Write;
+
+/// JSON output options
+#[derive(Debug, Clone, Default)]
+pub struct JsonWriteOptions {
+    pub pretty: bool,
+}
+
+impl JsonWriteOptions {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+enum Cell<'a> {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float32(f32),
+    Float64(f64),
+    Str(&'a str),
+    String(String),
+}
+
+fn cell<'a>(serie: &'a dyn Array, data_type: &DataType, index: usize) -> Result<Cell<'a>, Error> {
+    if serie.is_null(index) {
+        return Ok(Cell::Null);
+    }
+    macro_rules! arr {
+        ($kind: ty) => {
+            serie
+                .as_any()
+                .downcast_ref::<$kind>()
+                .ok_or(Error::TypeMismatch)?
+                .value(index)
+        };
+    }
+    let cell = match data_type {
+        DataType::Boolean => Cell::Bool(arr!(BooleanArray)),
+        DataType::Float32 => Cell::Float32(arr!(PrimitiveArray<f32>)),
+        DataType::Float64 => Cell::Float64(arr!(PrimitiveArray<f64>)),
+        DataType::Int8 => Cell::Int(arr!(PrimitiveArray<i8>).into()),
+        DataType::Int16 => Cell::Int(arr!(PrimitiveArray<i16>).into()),
+        DataType::Int32 => Cell::Int(arr!(PrimitiveArray<i32>).into()),
+        DataType::Int64 => Cell::Int(arr!(PrimitiveArray<i64>)),
+        DataType::UInt8 => Cell::UInt(arr!(PrimitiveArray<u8>).into()),
+        DataType::UInt16 => Cell::UInt(arr!(PrimitiveArray<u16>).into()),
+        DataType::UInt32 => Cell::UInt(arr!(PrimitiveArray<u32>).into()),
+        DataType::UInt64 => Cell::UInt(arr!(PrimitiveArray<u64>)),
+        DataType::Utf8 => Cell::Str(arr!(Utf8Array<i32>)),
+        DataType::LargeUtf8 => Cell::Str(arr!(Utf8Array<i64>)),
+        DataType::Timestamp(time_unit, _) => Cell::String(crate::timestamp_rfc3339(
+            arr!(PrimitiveArray<i64>),
+            time_unit,
+        )?),
+        v => {
+            return Err(Error::Unimplemented(format!("{:?}", v)));
+        }
+    };
+    Ok(cell)
+}
+
+#[cfg(feature = "serde_json")]
+impl Serialize for Cell<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Cell::Bool(v) => serializer.serialize_bool(*v),
+            Cell::Int(v) => serializer.serialize_i64(*v),
+            Cell::UInt(v) => serializer.serialize_u64(*v),
+            Cell::Float32(v) if v.is_finite() => serializer.serialize_f32(*v),
+            Cell::Float64(v) if v.is_finite() => serializer.serialize_f64(*v),
+            Cell::Str(v) => serializer.serialize_str(v),
+            Cell::String(v) => serializer.serialize_str(v),
+            Cell::Null | Cell::Float32(_) | Cell::Float64(_) => serializer.serialize_none(),
+        }
+    }
+}
+
+/// A single data frame row, serialized as an object with keys in the column order
+#[cfg(feature = "serde_json")]
+struct JsonRow<'a> {
+    df: &'a DataFrame,
+    row: usize,
+}
+
+#[cfg(feature = "serde_json")]
+impl Serialize for JsonRow<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.df.fields().len()))?;
+        for (serie, field) in self.df.data().iter().zip(self.df.fields()) {
+            let value =
+                cell(serie.as_ref(), &field.data_type, self.row).map_err(S::Error::custom)?;
+            map.serialize_entry(&field.name, &value)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde_json")]
+struct JsonRows<'a>(&'a DataFrame);
+
+#[cfg(feature = "serde_json")]
+impl Serialize for JsonRows<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let rows = self.0.rows().unwrap_or_default();
+        let mut seq = serializer.serialize_seq(Some(rows))?;
+        for row in 0..rows {
+            seq.serialize_element(&JsonRow { df: self.0, row })?;
+        }
+        seq.end()
+    }
+}
+
+impl DataFrame {
+    /// Serialize the data frame to JSON array of row objects with the default options
+    #[inline]
+    pub fn to_json(&self) -> Result<Vec<u8>, Error> {
+        self.to_json_with_options(&JsonWriteOptions::default())
+    }
+    /// Serialize the data frame to JSON array of row objects
+    ///
+    /// Object keys follow the column ordering. Nulls and non-finite floats are written as nulls,
+    /// timestamps as RFC3339 (UTC) strings.
+    ///
+    /// If "serde_json" feature is enabled, the output is produced with serde_json, otherwise the
+    /// built-in writer is used. The outputs differ only in the notation of very large and very
+    /// small float values.
+    pub fn to_json_with_options(&self, options: &JsonWriteOptions) -> Result<Vec<u8>, Error> {
+        let mut buf = Vec::new();
+        #[cfg(feature = "serde_json")]
+        {
+            if options.pretty {
+                serde_json::to_writer_pretty(&mut buf, &JsonRows(self))?;
+            } else {
+                serde_json::to_writer(&mut buf, &JsonRows(self))?;
+            }
+        }
+        #[cfg(not(feature = "serde_json"))]
+        write_json(self, &mut buf, options.pretty)?;
+        Ok(buf)
+    }
+}
+
+#[cfg(not(feature = "serde_json"))]
+fn write_json_str<W: Write>(w: &mut W, s: &str) -> Result<(), Error> {
+    w.write_all(b"\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => w.write_all(b"\\\"")?,
+            '\\' => w.write_all(b"\\\\")?,
+            '\n' => w.write_all(b"\\n")?,
+            '\r' => w.write_all(b"\\r")?,
+            '\t' => w.write_all(b"\\t")?,
+            '\u{8}' => w.write_all(b"\\b")?,
+            '\u{c}' => w.write_all(b"\\f")?,
+            c if c < ' ' => write!(w, "\\u{:04x}", u32::from(c))?,
+            c => w.write_all(c.encode_utf8(&mut [0; 4]).as_bytes())?,
+        }
+    }
+    w.write_all(b"\"")?;
+    Ok(())
+}
+
+#[cfg(not(feature = "serde_json"))]
+fn write_json<W: Write>(df: &DataFrame, w: &mut W, pretty: bool) -> Result<(), Error> {
+    let rows = df.rows().unwrap_or_default();
+    if rows == 0 {
+        w.write_all(b"[]")?;
+        return Ok(());
+    }
+    w.write_all(b"[")?;
+    for row in 0..rows {
+        if row > 0 {
+            w.write_all(b",")?;
+        }
+        if pretty {
+            w.write_all(b"\n  ")?;
+        }
+        w.write_all(b"{")?;
+        for (i, (serie, field)) in df.data().iter().zip(df.fields()).enumerate() {
+            if i > 0 {
+                w.write_all(b",")?;
+            }
+            if pretty {
+                w.write_all(b"\n    ")?;
+            }
+            write_json_str(w, &field.name)?;
+            w.write_all(b":")?;
+            if pretty {
+                w.write_all(b" ")?;
+            }
+            match cell(serie.as_ref(), &field.data_type, row)? {
+                Cell::Null => w.write_all(b"null")?,
+                Cell::Bool(v) => write!(w, "{}", v)?,
+                Cell::Int(v) => write!(w, "{}", v)?,
+                Cell::UInt(v) => write!(w, "{}", v)?,
+                // the debug format keeps the fraction part of integral values, as serde_json does
+                Cell::Float32(v) if v.is_finite() => write!(w, "{:?}", v)?,
+                Cell::Float64(v) if v.is_finite() => write!(w, "{:?}", v)?,
+                Cell::Float32(_) | Cell::Float64(_) => w.write_all(b"null")?,
+                Cell::Str(v) => write_json_str(w, v)?,
+                Cell::String(v) => write_json_str(w, &v)?,
+            }
+        }
+        if pretty {
+            w.write_all(b"\n  ")?;
+        }
+        w.write_all(b"}")?;
+    }
+    if pretty {
+        w.write_all(b"\n")?;
+    }
+    w.write_all(b"]")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow2::array::{Float64Array, Int64Array};
+    use arrow2::datatypes::TimeUnit;
+
+    #[test]
+    fn test_to_json() {
+        let mut df = DataFrame::new0();
+        df.add_series0("f", Float64Array::from_slice([1.5, f64::NAN]).boxed())
+            .unwrap();
+        df.add_series0(
+            "g",
+            Float64Array::from_slice([f64::INFINITY, f64::NEG_INFINITY]).boxed(),
+        )
+        .unwrap();
+        df.add_series0("i", Int64Array::from(vec![Some(1), None]).boxed())
+            .unwrap();
+        df.add_series0(
+            "t",
+            Int64Array::from_vec(vec![1_600_000_000_123, 0])
+                .to(DataType::Timestamp(TimeUnit::Millisecond, None))
+                .boxed(),
+        )
+        .unwrap();
+        df.add_series0(
+            "s",
+            Utf8Array::<i32>::from_slice(["a\"b\\c", "x\ny\u{1}\u{8}"]).boxed(),
+        )
+        .unwrap();
+        assert_eq!(
+            std::str::from_utf8(&df.to_json().unwrap()).unwrap(),
+            concat!(
+                r#"[{"f":1.5,"g":null,"i":1,"t":"2020-09-13T12:26:40.123Z","s":"a\"b\\c"},"#,
+                r#"{"f":null,"g":null,"i":null,"t":"1970-01-01T00:00:00Z","s":"x\ny\u0001\b"}]"#
+            )
+        );
+    }
+    #[test]
+    fn test_to_json_pretty() {
+        let mut df = DataFrame::new0();
+        df.add_series0("b", Float64Array::from_slice([2.0, 0.25]).boxed())
+            .unwrap();
+        df.add_series0("a", Utf8Array::<i32>::from(vec![Some("x"), None]).boxed())
+            .unwrap();
+        let options = JsonWriteOptions { pretty: true };
+        assert_eq!(
+            std::str::from_utf8(&df.to_json_with_options(&options).unwrap()).unwrap(),
+            concat!(
+                "[\n  {\n    \"b\": 2.0,\n    \"a\": \"x\"\n  },\n",
+                "  {\n    \"b\": 0.25,\n    \"a\": null\n  }\n]"
+            )
+        );
+        assert_eq!(DataFrame::new0().to_json().unwrap(), b"[]");
+        assert_eq!(
+            DataFrame::new0().to_json_with_options(&options).unwrap(),
+            b"[]"
+        );
+        let empty = df.try_sliced(0, 0).unwrap();
+        assert_eq!(empty.to_json().unwrap(), b"[]");
+    }
+}
@@ -1,2 +1,9 @@
+pub mod csv;
 #[cfg(feature = "json")]
 pub mod json;
+pub mod json_writer;
+#[cfg(feature = "parquet")]
+pub mod parquet;
+pub mod records;
+#[cfg(feature = "serde")]
+mod serialize;
@@ -0,0 +1,292 @@
+#[cfg(feature = "arrow2_ih")]
+extern crate arrow2_ih as arrow2;
+
+use crate::df::{Chunk, DataFrame, Series};
+use crate::Error;
+use arrow2::array::{new_empty_array, Array};
+use arrow2::compute::concatenate::concatenate;
+use arrow2::datatypes::DataType;
+use arrow2::io::parquet::read;
+use arrow2::io::parquet::write::{transverse, FileWriter, RowGroupIterator};
+pub use arrow2::io::parquet::write::{CompressionOptions, Encoding, Version, WriteOptions};
+use std::io::{Read, Seek, Write};
+use std::path::Path;
+
+/// Parquet output options
+///
+/// Compressions other than uncompressed require the corresponding arrow2 compression features
+#[derive(Debug, Clone)]
+pub struct ParquetWriteOptions {
+    pub options: WriteOptions,
+    /// encoding, applied to all columns
+    pub encoding: Encoding,
+}
+
+impl Default for ParquetWriteOptions {
+    fn default() -> Self {
+        Self {
+            options: WriteOptions {
+                write_statistics: true,
+                compression: CompressionOptions::Uncompressed,
+                version: Version::V2,
+                data_pagesize_limit: None,
+            },
+            encoding: Encoding::Plain,
+        }
+    }
+}
+
+impl ParquetWriteOptions {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_compression(mut self, compression: CompressionOptions) -> Self {
+        self.options.compression = compression;
+        self
+    }
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+}
+
+impl DataFrame {
+    /// Serialize the data frame to Parquet file bytes with the default options
+    #[inline]
+    pub fn to_parquet(&self) -> Result<Vec<u8>, Error> {
+        self.to_parquet_with_options(&ParquetWriteOptions::default())
+    }
+    /// Serialize the data frame to Parquet file bytes
+    ///
+    /// Arrow schema is stored in the file metadata, so timestamp units and time zones are
+    /// restored as-is when read back
+    pub fn to_parquet_with_options(&self, options: &ParquetWriteOptions) -> Result<Vec<u8>, Error> {
+        let mut buf = Vec::new();
+        write_parquet_into(self, &mut buf, options)?;
+        Ok(buf)
+    }
+    /// Parse a data frame from Parquet file bytes
+    #[inline]
+    pub fn from_parquet(bytes: &[u8]) -> Result<Self, Error> {
+        read_parquet_from(std::io::Cursor::new(bytes), None)
+    }
+    /// Read a data frame from a Parquet file
+    ///
+    /// Row groups are concatenated into a single data frame. Nested fields are not supported.
+    pub fn read_parquet(path: &Path) -> Result<Self, Error> {
+        let file = std::fs::File::open(path)?;
+        read_parquet_from(std::io::BufReader::new(file), None)
+    }
+    /// Read the specified columns only from a Parquet file
+    pub fn read_parquet_projected(path: &Path, columns: &[&str]) -> Result<Self, Error> {
+        let file = std::fs::File::open(path)?;
+        read_parquet_from(std::io::BufReader::new(file), Some(columns))
+    }
+    /// Write the data frame to a Parquet file with the default options
+    #[inline]
+    pub fn write_parquet(&self, path: &Path) -> Result<(), Error> {
+        self.write_parquet_with_options(path, &ParquetWriteOptions::default())
+    }
+    /// Write the data frame to a Parquet file
+    pub fn write_parquet_with_options(
+        &self,
+        path: &Path,
+        options: &ParquetWriteOptions,
+    ) -> Result<(), Error> {
+        let file = std::fs::File::create(path)?;
+        write_parquet_into(self, std::io::BufWriter::new(file), options)
+    }
+}
+
+fn write_parquet_into<W: Write>(
+    df: &DataFrame,
+    writer: W,
+    options: &ParquetWriteOptions,
+) -> Result<(), Error> {
+    let schema = df.schema();
+    let chunk = Chunk::new(df.data().to_vec());
+    let encodings = schema
+        .fields
+        .iter()
+        .map(|f| transverse(&f.data_type, |_| options.encoding))
+        .collect();
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk)].into_iter(),
+        &schema,
+        options.options,
+        encodings,
+    )?;
+    let mut file_writer = FileWriter::try_new(writer, schema, options.options)?;
+    for group in row_groups {
+        file_writer.write(group?)?;
+    }
+    file_writer.end(None)?;
+    Ok(())
+}
+
+fn read_parquet_from<R: Read + Seek>(
+    mut reader: R,
+    columns: Option<&[&str]>,
+) -> Result<DataFrame, Error> {
+    let metadata = read::read_metadata(&mut reader)?;
+    let mut schema = read::infer_schema(&metadata)?;
+    if let Some(cols) = columns {
+        for col in cols {
+            if !schema.fields.iter().any(|f| f.name == *col) {
+                return Err(Error::NotFound((*col).to_owned()));
+            }
+        }
+        schema = schema.filter(|_, field| cols.contains(&field.name.as_str()));
+    }
+    for field in &schema.fields {
+        if matches!(
+            field.data_type.to_logical_type(),
+            DataType::List(_)
+                | DataType::LargeList(_)
+                | DataType::FixedSizeList(_, _)
+                | DataType::Struct(_)
+                | DataType::Map(_, _)
+                | DataType::Union(_, _, _)
+        ) {
+            return Err(Error::Unimplemented(format!(
+                "nested field {}: {:?}",
+                field.name, field.data_type
+            )));
+        }
+    }
+    let reader = read::FileReader::new(
+        reader,
+        metadata.row_groups,
+        schema.clone(),
+        None,
+        None,
+        None,
+    );
+    let mut chunks = Vec::new();
+    for chunk in reader {
+        chunks.push(chunk?);
+    }
+    let mut data: Vec<Series> = Vec::with_capacity(schema.fields.len());
+    for (i, field) in schema.fields.iter().enumerate() {
+        let arrays: Vec<&dyn Array> = chunks.iter().map(|c| c.arrays()[i].as_ref()).collect();
+        data.push(match arrays.len() {
+            0 => new_empty_array(field.data_type.clone()),
+            1 => arrays[0].to_boxed(),
+            _ => concatenate(&arrays)?,
+        });
+    }
+    DataFrame::from_parts(schema.fields, data, Some(schema.metadata))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow2::array::{Float64Array, Int64Array, ListArray, Utf8Array};
+    use arrow2::datatypes::TimeUnit;
+    use arrow2::offset::Offsets;
+
+    fn sample_df() -> DataFrame {
+        let mut df = DataFrame::new0();
+        df.add_series0(
+            "f",
+            Float64Array::from(vec![Some(1.5), None, Some(-2.0)]).boxed(),
+        )
+        .unwrap();
+        df.add_series0(
+            "s",
+            Utf8Array::<i32>::from(vec![Some("x"), Some("y"), None]).boxed(),
+        )
+        .unwrap();
+        df.add_series0(
+            "t",
+            Int64Array::from(vec![Some(1_000_000), None, Some(1_700_000_000_000_000)])
+                .to(DataType::Timestamp(
+                    TimeUnit::Microsecond,
+                    Some("UTC".to_owned()),
+                ))
+                .boxed(),
+        )
+        .unwrap();
+        df
+    }
+
+    #[test]
+    fn test_parquet_round_trip() {
+        let df = sample_df();
+        let parsed = DataFrame::from_parquet(&df.to_parquet().unwrap()).unwrap();
+        assert!(parsed == df);
+        assert_eq!(
+            parsed.fields()[2].data_type,
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".to_owned()))
+        );
+        assert_eq!(parsed.null_count("f").unwrap(), 1);
+        let options = ParquetWriteOptions::new().with_encoding(Encoding::Plain);
+        let path =
+            std::env::temp_dir().join(format!("arrow_util_rt_{}.parquet", std::process::id()));
+        df.write_parquet_with_options(&path, &options).unwrap();
+        let parsed = DataFrame::read_parquet(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(parsed == df);
+    }
+
+    #[test]
+    fn test_parquet_row_groups() {
+        let df = sample_df();
+        let schema = df.schema();
+        let options = ParquetWriteOptions::default();
+        let encodings = schema
+            .fields
+            .iter()
+            .map(|f| transverse(&f.data_type, |_| options.encoding))
+            .collect();
+        let chunk = Chunk::new(df.data().to_vec());
+        let row_groups = RowGroupIterator::try_new(
+            vec![Ok(chunk.clone()), Ok(chunk)].into_iter(),
+            &schema,
+            options.options,
+            encodings,
+        )
+        .unwrap();
+        let mut writer = FileWriter::try_new(Vec::new(), schema, options.options).unwrap();
+        for group in row_groups {
+            writer.write(group.unwrap()).unwrap();
+        }
+        writer.end(None).unwrap();
+        let parsed = DataFrame::from_parquet(&writer.into_inner()).unwrap();
+        let mut expected = df.clone();
+        expected.append(&df).unwrap();
+        assert_eq!(parsed.rows(), Some(6));
+        assert!(parsed == expected);
+    }
+    #[test]
+    fn test_parquet_projected() {
+        let path =
+            std::env::temp_dir().join(format!("arrow_util_proj_{}.parquet", std::process::id()));
+        sample_df().write_parquet(&path).unwrap();
+        let parsed = DataFrame::read_parquet_projected(&path, &["t", "f"]);
+        let missing = DataFrame::read_parquet_projected(&path, &["f", "x"]);
+        std::fs::remove_file(&path).unwrap();
+        let parsed = parsed.unwrap();
+        assert_eq!(parsed.names(), vec!["f", "t"]);
+        assert_eq!(parsed.rows(), Some(3));
+        assert!(matches!(missing, Err(Error::NotFound(n)) if n == "x"));
+    }
+    #[test]
+    fn test_parquet_nested() {
+        let values = Int64Array::from_vec(vec![1, 2, 3]).boxed();
+        let list = ListArray::<i32>::new(
+            ListArray::<i32>::default_datatype(DataType::Int64),
+            Offsets::try_from(vec![0, 1, 3]).unwrap().into(),
+            values,
+            None,
+        );
+        let mut df = DataFrame::new0();
+        df.add_series0("l", list.boxed()).unwrap();
+        let bytes = df.to_parquet().unwrap();
+        assert!(matches!(
+            DataFrame::from_parquet(&bytes),
+            Err(Error::Unimplemented(_))
+        ));
+    }
+}
@@ -0,0 +1,150 @@
+#[cfg(feature = "arrow2_ih")]
+extern crate arrow2_ih as arrow2;
+
+use crate::convert::csv::{parse_series, write_value};
+use crate::df::DataFrame;
+use crate::Error;
+use arrow2::datatypes::{DataType, Field, Schema};
+use std::collections::{BTreeSet, HashMap};
+
+impl DataFrame {
+    /// Create a data frame from string records
+    ///
+    /// If the schema is specified, the values are parsed to the schema types (the same way as in
+    /// [`DataFrame::from_csv`]) and keys not in the schema are ignored. Otherwise all keys (sorted)
+    /// become Utf8 columns. Missing keys produce nulls
+    pub fn from_record_vec(
+        records: &[HashMap<String, String>],
+        schema: Option<&Schema>,
+    ) -> Result<Self, Error> {
+        let (fields, metadata) = if let Some(s) = schema {
+            (s.fields.clone(), Some(s.metadata.clone()))
+        } else {
+            let names: BTreeSet<&str> = records
+                .iter()
+                .flat_map(|r| r.keys().map(String::as_str))
+                .collect();
+            (
+                names
+                    .into_iter()
+                    .map(|n| Field::new(n, DataType::Utf8, true))
+                    .collect(),
+                None,
+            )
+        };
+        let mut data = Vec::with_capacity(fields.len());
+        for field in &fields {
+            let values: Vec<Option<&str>> = records
+                .iter()
+                .map(|r| r.get(&field.name).map(String::as_str))
+                .collect();
+            data.push(parse_series(&values, field.data_type())?);
+        }
+        DataFrame::from_parts(fields, data, metadata)
+    }
+    /// Convert the data frame into string records, the values are formatted the same way as in
+    /// [`DataFrame::to_csv`] (without quoting), nulls are mapped to None
+    ///
+    /// Values of types, not supported by CSV, are formatted with arrow2 display
+    pub fn to_record_vec(&self) -> Vec<HashMap<String, Option<String>>> {
+        let rows = self.rows().unwrap_or_default();
+        let mut records = vec![HashMap::with_capacity(self.fields().len()); rows];
+        for (field, serie) in self.fields().iter().zip(self.data()) {
+            let display = arrow2::array::get_display(serie.as_ref(), "");
+            for (i, record) in records.iter_mut().enumerate() {
+                let value = if serie.is_null(i) {
+                    None
+                } else {
+                    let mut buf = Vec::new();
+                    write_value(&mut buf, serie.as_ref(), field.data_type(), i, None)
+                        .ok()
+                        .and_then(|()| String::from_utf8(buf).ok())
+                        .or_else(|| {
+                            let mut s = String::new();
+                            display(&mut s, i).ok().map(|()| s)
+                        })
+                };
+                record.insert(field.name.clone(), value);
+            }
+        }
+        records
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow2::array::{Float64Array, Int64Array, Utf8Array};
+    use arrow2::datatypes::TimeUnit;
+
+    fn record(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn test_from_record_vec() {
+        let records = [
+            record(&[("a", "1"), ("s", "x")]),
+            record(&[("a", "2.5"), ("extra", "e")]),
+            record(&[("a", "bad")]),
+        ];
+        let df = DataFrame::from_record_vec(&records, None).unwrap();
+        assert_eq!(df.names(), ["a", "extra", "s"]);
+        assert!(df.fields().iter().all(|f| f.data_type() == &DataType::Utf8));
+        assert_eq!(
+            df.get_utf8_iter("extra").unwrap().collect::<Vec<_>>(),
+            [None, Some("e"), None]
+        );
+        let schema = Schema::from(vec![
+            Field::new("a", DataType::Float64, true),
+            Field::new("s", DataType::Utf8, true),
+            Field::new("m", DataType::Int64, true),
+        ]);
+        let df = DataFrame::from_record_vec(&records, Some(&schema)).unwrap();
+        assert_eq!(df.names(), ["a", "s", "m"]);
+        assert_eq!(
+            df.get_float64("a").unwrap().iter().collect::<Vec<_>>(),
+            [Some(&1.0), Some(&2.5), None]
+        );
+        assert_eq!(
+            df.get_utf8_iter("s").unwrap().collect::<Vec<_>>(),
+            [Some("x"), None, None]
+        );
+        assert_eq!(df.get_int64("m").unwrap().null_count(), 3);
+        let df = DataFrame::from_record_vec(&[], Some(&schema)).unwrap();
+        assert_eq!(df.rows(), Some(0));
+    }
+
+    #[test]
+    fn test_to_record_vec() {
+        let mut df = DataFrame::new0();
+        df.add_series0("f", Float64Array::from(vec![Some(1.5), Some(2.0)]).boxed())
+            .unwrap();
+        df.add_series0("i", Int64Array::from(vec![Some(-3), None]).boxed())
+            .unwrap();
+        df.add_series0(
+            "t",
+            Int64Array::from_vec(vec![1_600_000_000, 0])
+                .to(DataType::Timestamp(TimeUnit::Second, None))
+                .boxed(),
+        )
+        .unwrap();
+        df.add_series0("s", Utf8Array::<i32>::from(vec![None, Some("a,b")]).boxed())
+            .unwrap();
+        let records = df.to_record_vec();
+        assert_eq!(records.len(), 2);
+        let value = |row: usize, name: &str| records[row][name].as_deref();
+        assert_eq!(value(0, "f"), Some("1.5"));
+        assert_eq!(value(1, "f"), Some("2"));
+        assert_eq!(value(0, "i"), Some("-3"));
+        assert_eq!(value(1, "i"), None);
+        assert_eq!(value(0, "t"), Some("2020-09-13T12:26:40Z"));
+        assert_eq!(value(1, "t"), Some("1970-01-01T00:00:00Z"));
+        assert_eq!(value(0, "s"), None);
+        assert_eq!(value(1, "s"), Some("a,b"));
+        assert!(DataFrame::new0().to_record_vec().is_empty());
+    }
+}
@@ -0,0 +1,387 @@
+#[cfg(feature = "arrow2_ih")]
+extern crate arrow2_ih as arrow2;
+
+use crate::df::{DataFrame, Series};
+use crate::Error;
+use arrow2::array::{Array, BooleanArray, PrimitiveArray, Utf8Array};
+use arrow2::datatypes::{DataType, Field, TimeUnit};
+use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, SerializeSeq, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+const FIELDS: &[&str] = &["schema", "data"];
+
+#[derive(Serialize)]
+struct FieldSer<'a> {
+    name: &'a str,
+    data_type_str: String,
+    nullable: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FieldDe {
+    name: String,
+    data_type_str: String,
+    #[serde(default = "default_nullable")]
+    nullable: bool,
+}
+
+#[inline]
+fn default_nullable() -> bool {
+    true
+}
+
+fn time_unit_str(time_unit: &TimeUnit) -> &'static str {
+    match time_unit {
+        TimeUnit::Second => "s",
+        TimeUnit::Millisecond => "ms",
+        TimeUnit::Microsecond => "us",
+        TimeUnit::Nanosecond => "ns",
+    }
+}
+
+fn data_type_to_str(data_type: &DataType) -> Result<String, Error> {
+    match data_type {
+        DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Utf8
+        | DataType::LargeUtf8 => Ok(format!("{:?}", data_type)),
+        DataType::Timestamp(time_unit, None) => {
+            Ok(format!("Timestamp({})", time_unit_str(time_unit)))
+        }
+        DataType::Timestamp(time_unit, Some(tz)) => {
+            Ok(format!("Timestamp({},{})", time_unit_str(time_unit), tz))
+        }
+        v => Err(Error::Unimplemented(format!("{:?}", v))),
+    }
+}
+
+fn data_type_from_str(s: &str) -> Result<DataType, Error> {
+    let data_type = match s {
+        "Boolean" => DataType::Boolean,
+        "Int8" => DataType::Int8,
+        "Int16" => DataType::Int16,
+        "Int32" => DataType::Int32,
+        "Int64" => DataType::Int64,
+        "UInt8" => DataType::UInt8,
+        "UInt16" => DataType::UInt16,
+        "UInt32" => DataType::UInt32,
+        "UInt64" => DataType::UInt64,
+        "Float32" => DataType::Float32,
+        "Float64" => DataType::Float64,
+        "Utf8" => DataType::Utf8,
+        "LargeUtf8" => DataType::LargeUtf8,
+        _ => {
+            let params = s
+                .strip_prefix("Timestamp(")
+                .and_then(|v| v.strip_suffix(')'))
+                .ok_or_else(|| Error::Unimplemented(s.to_owned()))?;
+            let (unit, tz) = params
+                .split_once(',')
+                .map_or((params, None), |(u, tz)| (u, Some(tz.to_owned())));
+            let time_unit = match unit {
+                "s" => TimeUnit::Second,
+                "ms" => TimeUnit::Millisecond,
+                "us" => TimeUnit::Microsecond,
+                "ns" => TimeUnit::Nanosecond,
+                v => return Err(Error::ParseError(format!("invalid time unit: {}", v))),
+            };
+            DataType::Timestamp(time_unit, tz)
+        }
+    };
+    Ok(data_type)
+}
+
+struct Columns<'a>(&'a DataFrame);
+
+impl Serialize for Columns<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.data().len()))?;
+        for (serie, field) in self.0.data().iter().zip(self.0.fields()) {
+            seq.serialize_element(&Column {
+                serie: serie.as_ref(),
+                data_type: &field.data_type,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+struct Column<'a> {
+    serie: &'a dyn Array,
+    data_type: &'a DataType,
+}
+
+impl Serialize for Column<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        macro_rules! ser {
+            ($kind: ty) => {
+                serializer.collect_seq(
+                    self.serie
+                        .as_any()
+                        .downcast_ref::<$kind>()
+                        .ok_or_else(|| <S::Error as ser::Error>::custom(Error::TypeMismatch))?
+                        .iter(),
+                )
+            };
+        }
+        match self.data_type {
+            DataType::Boolean => ser!(BooleanArray),
+            DataType::Int8 => ser!(PrimitiveArray<i8>),
+            DataType::Int16 => ser!(PrimitiveArray<i16>),
+            DataType::Int32 => ser!(PrimitiveArray<i32>),
+            DataType::Int64 | DataType::Timestamp(_, _) => ser!(PrimitiveArray<i64>),
+            DataType::UInt8 => ser!(PrimitiveArray<u8>),
+            DataType::UInt16 => ser!(PrimitiveArray<u16>),
+            DataType::UInt32 => ser!(PrimitiveArray<u32>),
+            DataType::UInt64 => ser!(PrimitiveArray<u64>),
+            DataType::Float32 => ser!(PrimitiveArray<f32>),
+            DataType::Float64 => ser!(PrimitiveArray<f64>),
+            DataType::Utf8 => ser!(Utf8Array<i32>),
+            DataType::LargeUtf8 => ser!(Utf8Array<i64>),
+            v => Err(ser::Error::custom(Error::Unimplemented(format!("{:?}", v)))),
+        }
+    }
+}
+
+/// The data frame is serialized as a struct with "schema" (array of field name, data type and
+/// nullable flag) and "data" (array of columns) fields. Metadata is not serialized.
+impl Serialize for DataFrame {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let schema = self
+            .fields()
+            .iter()
+            .map(|f| {
+                Ok(FieldSer {
+                    name: &f.name,
+                    data_type_str: data_type_to_str(&f.data_type)?,
+                    nullable: f.is_nullable,
+                })
+            })
+            .collect::<Result<Vec<FieldSer>, Error>>()
+            .map_err(<S::Error as ser::Error>::custom)?;
+        let mut st = serializer.serialize_struct("DataFrame", 2)?;
+        st.serialize_field("schema", &schema)?;
+        st.serialize_field("data", &Columns(self))?;
+        st.end()
+    }
+}
+
+fn into_fields(schema: Vec<FieldDe>) -> Result<Vec<Field>, Error> {
+    schema
+        .into_iter()
+        .map(|f| {
+            Ok(Field::new(
+                f.name,
+                data_type_from_str(&f.data_type_str)?,
+                f.nullable,
+            ))
+        })
+        .collect()
+}
+
+struct ColumnSeed<'a>(&'a DataType);
+
+impl<'de> DeserializeSeed<'de> for ColumnSeed<'_> {
+    type Value = Series;
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Series, D::Error> {
+        macro_rules! prim {
+            ($kind: ty) => {
+                PrimitiveArray::<$kind>::from(Vec::<Option<$kind>>::deserialize(deserializer)?)
+                    .boxed()
+            };
+        }
+        let series = match self.0 {
+            DataType::Boolean => {
+                BooleanArray::from(Vec::<Option<bool>>::deserialize(deserializer)?).boxed()
+            }
+            DataType::Int8 => prim!(i8),
+            DataType::Int16 => prim!(i16),
+            DataType::Int32 => prim!(i32),
+            DataType::Int64 => prim!(i64),
+            DataType::UInt8 => prim!(u8),
+            DataType::UInt16 => prim!(u16),
+            DataType::UInt32 => prim!(u32),
+            DataType::UInt64 => prim!(u64),
+            DataType::Float32 => prim!(f32),
+            DataType::Float64 => prim!(f64),
+            DataType::Timestamp(_, _) => {
+                PrimitiveArray::<i64>::from(Vec::<Option<i64>>::deserialize(deserializer)?)
+                    .to(self.0.clone())
+                    .boxed()
+            }
+            DataType::Utf8 => {
+                Utf8Array::<i32>::from(Vec::<Option<String>>::deserialize(deserializer)?).boxed()
+            }
+            DataType::LargeUtf8 => {
+                Utf8Array::<i64>::from(Vec::<Option<String>>::deserialize(deserializer)?).boxed()
+            }
+            v => {
+                return Err(de::Error::custom(Error::Unimplemented(format!("{:?}", v))));
+            }
+        };
+        Ok(series)
+    }
+}
+
+struct ColumnsSeed<'a>(&'a [Field]);
+
+impl<'de> DeserializeSeed<'de> for ColumnsSeed<'_> {
+    type Value = Vec<Series>;
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Vec<Series>, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ColumnsSeed<'_> {
+    type Value = Vec<Series>;
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "array of {} columns", self.0.len())
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<Series>, A::Error> {
+        let mut data = Vec::with_capacity(self.0.len());
+        for (i, field) in self.0.iter().enumerate() {
+            data.push(
+                seq.next_element_seed(ColumnSeed(&field.data_type))?
+                    .ok_or_else(|| <A::Error as de::Error>::invalid_length(i, &self))?,
+            );
+        }
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(self.0.len() + 1, &self));
+        }
+        Ok(data)
+    }
+}
+
+struct DataFrameVisitor;
+
+impl<'de> Visitor<'de> for DataFrameVisitor {
+    type Value = DataFrame;
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("data frame schema and data")
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<DataFrame, A::Error> {
+        let schema: Vec<FieldDe> = seq
+            .next_element()?
+            .ok_or_else(|| <A::Error as de::Error>::invalid_length(0, &self))?;
+        let fields = into_fields(schema).map_err(<A::Error as de::Error>::custom)?;
+        let data = seq
+            .next_element_seed(ColumnsSeed(&fields))?
+            .ok_or_else(|| <A::Error as de::Error>::invalid_length(1, &self))?;
+        DataFrame::from_parts(fields, data, None).map_err(de::Error::custom)
+    }
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<DataFrame, A::Error> {
+        let mut fields: Option<Vec<Field>> = None;
+        let mut data: Option<Vec<Series>> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "schema" => {
+                    let schema: Vec<FieldDe> = map.next_value()?;
+                    fields.replace(into_fields(schema).map_err(<A::Error as de::Error>::custom)?);
+                }
+                "data" => {
+                    let f = fields.as_ref().ok_or_else(|| {
+                        <A::Error as de::Error>::custom("schema must precede data")
+                    })?;
+                    data.replace(map.next_value_seed(ColumnsSeed(f))?);
+                }
+                v => return Err(de::Error::unknown_field(v, FIELDS)),
+            }
+        }
+        let fields = fields.ok_or_else(|| <A::Error as de::Error>::missing_field("schema"))?;
+        let data = data.ok_or_else(|| <A::Error as de::Error>::missing_field("data"))?;
+        DataFrame::from_parts(fields, data, None).map_err(de::Error::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for DataFrame {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("DataFrame", FIELDS, DataFrameVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "serde_json")]
+    fn test_serde_round_trip() {
+        let mut df = DataFrame::new0();
+        df.add_series0("b", BooleanArray::from(vec![Some(true), None]).boxed())
+            .unwrap();
+        df.add_series0(
+            "i",
+            PrimitiveArray::<i32>::from(vec![None, Some(-1)]).boxed(),
+        )
+        .unwrap();
+        df.add_series0(
+            "f",
+            PrimitiveArray::<f64>::from(vec![Some(1.5), None]).boxed(),
+        )
+        .unwrap();
+        df.add_series0("s", Utf8Array::<i64>::from(vec![Some("x"), None]).boxed())
+            .unwrap();
+        df.add_series0(
+            "t",
+            PrimitiveArray::<i64>::from(vec![Some(1), None])
+                .to(DataType::Timestamp(
+                    TimeUnit::Millisecond,
+                    Some("UTC".to_owned()),
+                ))
+                .boxed(),
+        )
+        .unwrap();
+        let s = serde_json::to_string(&df).unwrap();
+        assert!(
+            s.starts_with(r#"{"schema":[{"name":"b","data_type_str":"Boolean","nullable":true}"#)
+        );
+        assert!(s.ends_with(r#""data":[[true,null],[null,-1],[1.5,null],["x",null],[1,null]]}"#));
+        let parsed: DataFrame = serde_json::from_str(&s).unwrap();
+        assert!(parsed == df);
+        assert_eq!(
+            parsed.fields()[4].data_type,
+            DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".to_owned()))
+        );
+    }
+    #[test]
+    #[cfg(feature = "serde_json")]
+    fn test_serde_errors() {
+        assert!(serde_json::from_str::<DataFrame>(
+            r#"{"data":[[1]],"schema":[{"name":"a","data_type_str":"Int64"}]}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<DataFrame>(
+            r#"{"schema":[{"name":"a","data_type_str":"Int64"}],"data":[[1],[2]]}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<DataFrame>(
+            r#"{"schema":[{"name":"a","data_type_str":"Int64"},{"name":"b","data_type_str":"Int64"}],"data":[[1],[2,3]]}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<DataFrame>(
+            r#"{"schema":[{"name":"a","data_type":"Int64"}],"data":[[1]]}"#
+        )
+        .is_err());
+        let df: DataFrame = serde_json::from_str(
+            r#"{"schema":[{"name":"a","data_type_str":"Timestamp(us)","nullable":false}],"data":[[1]]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            df.fields()[0].data_type,
+            DataType::Timestamp(TimeUnit::Microsecond, None)
+        );
+        assert!(!df.fields()[0].is_nullable);
+    }
+}
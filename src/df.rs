@@ -2,19 +2,23 @@
 extern crate arrow2_ih as arrow2;
 
 use crate::{Error, Time, TimeZone};
-#[cfg(feature = "json")]
-use arrow2::array::BooleanArray;
-use arrow2::array::{Array, Int64Array, PrimitiveArray, Utf8Array};
+use arrow2::array::{
+    Array, BooleanArray, Float64Array, Int32Array, Int64Array, PrimitiveArray, Utf8Array,
+};
+use arrow2::bitmap::{Bitmap, MutableBitmap};
 pub use arrow2::chunk::Chunk;
+use arrow2::compute;
 use arrow2::datatypes::Field;
 pub use arrow2::datatypes::{DataType, Metadata, Schema, TimeUnit};
 use arrow2::error::Error as ArrowError;
-use arrow2::io::ipc::read::{StreamReader, StreamState};
-use arrow2::io::ipc::write::{StreamWriter, WriteOptions};
+use arrow2::io::ipc::read::{read_file_metadata, FileReader, StreamReader, StreamState};
+use arrow2::io::ipc::write::{Compression, FileWriter, StreamWriter, WriteOptions};
 use arrow2::types::NativeType;
-use chrono::{DateTime, Local, NaiveDateTime, SecondsFormat, Utc};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+use std::collections::HashMap;
 use std::fmt;
 use std::ops::{Add, Div, Mul, Sub};
+use std::path::Path;
 use std::str::FromStr;
 
 /// Series type, alias for boxed arrow2 array
@@ -94,6 +98,28 @@ impl DataFrame {
         .unwrap();
         df
     }
+    /// Create a new time-series data frame from i64 timestamps, the values are used as-is
+    ///
+    /// # Panics
+    ///
+    /// should not panic
+    pub fn new_timeseries_from_i64(
+        time_series: Vec<i64>,
+        cols: Option<usize>,
+        tz: TimeZone,
+        time_unit: TimeUnit,
+    ) -> Self {
+        let mut df = Self::new(cols.map(|c| c + 1));
+        let ts = Int64Array::from_vec(time_series).boxed();
+        df.add_series(
+            "time",
+            ts,
+            Some(DataType::Timestamp(time_unit, tz.into())),
+            None,
+        )
+        .unwrap();
+        df
+    }
     /// Create a new time-series data frame from f64 timestamps and convert them to rfc3339 strings
     ///
     /// # Panics
@@ -151,6 +177,34 @@ impl DataFrame {
             metadata: metadata.unwrap_or_default(),
         })
     }
+    /// Create a data frame of Float64 columns from a hash map, the columns are sorted by name
+    pub fn from_float_hashmap(data: HashMap<String, Vec<Option<f64>>>) -> Result<Self, Error> {
+        let mut columns: Vec<(String, Vec<Option<f64>>)> = data.into_iter().collect();
+        columns.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut df = DataFrame::new(Some(columns.len()));
+        for (name, values) in columns {
+            df.add_series(
+                &name,
+                Float64Array::from(values).boxed(),
+                Some(DataType::Float64),
+                None,
+            )?;
+        }
+        Ok(df)
+    }
+    /// Convert a data frame of Float64 columns into a hash map
+    pub fn to_float_hashmap(&self) -> Result<HashMap<String, Vec<Option<f64>>>, Error> {
+        self.fields
+            .iter()
+            .map(|field| {
+                let values = self.get_float64(&field.name)?;
+                Ok((
+                    field.name.clone(),
+                    values.iter().map(|v| v.copied()).collect(),
+                ))
+            })
+            .collect()
+    }
     /// Split the data frame into vector of fields, vector of series and metadata
     pub fn into_parts(self) -> (Vec<Field>, Vec<Series>, Metadata) {
         (self.fields, self.data, self.metadata)
@@ -299,6 +353,219 @@ impl DataFrame {
             Err(Error::OutOfBounds)
         }
     }
+    /// Create a new data frame of the first n rows
+    ///
+    /// # Panics
+    ///
+    /// should not panic
+    pub fn head(&self, n: usize) -> Self {
+        let rows = self.rows().unwrap_or_default();
+        self.try_sliced(0, n.min(rows)).unwrap()
+    }
+    /// Create a new data frame of the last n rows
+    ///
+    /// # Panics
+    ///
+    /// should not panic
+    pub fn tail(&self, n: usize) -> Self {
+        let rows = self.rows().unwrap_or_default();
+        let n = n.min(rows);
+        self.try_sliced(rows - n, n).unwrap()
+    }
+    /// Create IPC chunk of the first n rows
+    ///
+    /// # Panics
+    ///
+    /// should not panic
+    pub fn head_chunk(&self, n: usize) -> Chunk<Box<dyn Array>> {
+        let rows = self.rows().unwrap_or_default();
+        self.try_chunk_sliced(0, n.min(rows)).unwrap()
+    }
+    /// Create IPC chunk of the last n rows
+    ///
+    /// # Panics
+    ///
+    /// should not panic
+    pub fn tail_chunk(&self, n: usize) -> Chunk<Box<dyn Array>> {
+        let rows = self.rows().unwrap_or_default();
+        let n = n.min(rows);
+        self.try_chunk_sliced(rows - n, n).unwrap()
+    }
+    /// Create a new data frame with rows at the specified indices
+    ///
+    /// The indices may repeat and be in any order, null indices produce null rows
+    pub fn take(&self, indices: &Int32Array) -> Result<Self, Error> {
+        let rows = self.rows().unwrap_or_default();
+        if indices
+            .iter()
+            .flatten()
+            .any(|i| !matches!(usize::try_from(*i), Ok(i) if i < rows))
+        {
+            return Err(Error::OutOfBounds);
+        }
+        let mut df = self.clone();
+        df.take_in_place(indices)?;
+        Ok(df)
+    }
+    /// Create a new data frame of n random rows, selected without replacement
+    ///
+    /// If the seed is not set, the thread-local random generator is used
+    #[cfg(feature = "rand")]
+    pub fn sample(&self, n: usize, seed: Option<u64>) -> Result<Self, Error> {
+        use rand::SeedableRng;
+        let rows = self.rows().unwrap_or_default();
+        if n > rows {
+            return Err(Error::OutOfBounds);
+        }
+        let indices = if let Some(s) = seed {
+            rand::seq::index::sample(&mut rand::rngs::StdRng::seed_from_u64(s), rows, n)
+        } else {
+            rand::seq::index::sample(&mut rand::thread_rng(), rows, n)
+        };
+        let indices = indices
+            .into_iter()
+            .map(|i| i32::try_from(i).map_err(|_| Error::OutOfBounds))
+            .collect::<Result<Vec<i32>, Error>>()?;
+        self.take(&Int32Array::from_vec(indices))
+    }
+    /// Create a new data frame with rows matching the mask
+    ///
+    /// Null mask values are treated as false
+    pub fn filter(&self, mask: &BooleanArray) -> Result<Self, Error> {
+        if self.data.is_empty() {
+            Ok(Self::new0())
+        } else if mask.len() == self.data[0].len() {
+            let data = self
+                .data
+                .iter()
+                .map(|d| compute::filter::filter(d.as_ref(), mask))
+                .collect::<Result<Vec<Series>, ArrowError>>()?;
+            Ok(Self {
+                data,
+                fields: self.fields.clone(),
+                metadata: self.metadata.clone(),
+            })
+        } else {
+            Err(Error::RowsNotMatch)
+        }
+    }
+    /// Create a new data frame with rows which have no nulls in any column
+    ///
+    /// # Panics
+    ///
+    /// should not panic
+    pub fn drop_nulls(&self) -> Self {
+        self.drop_nulls_in(&self.names()).unwrap()
+    }
+    /// Create a new data frame with rows which have no nulls in the specified columns
+    pub fn drop_nulls_in(&self, columns: &[&str]) -> Result<Self, Error> {
+        let mut validity: Option<Bitmap> = None;
+        for col in columns {
+            let serie = &self.data[self.column_index(col)?];
+            let col_validity = if serie.data_type() == &DataType::Null {
+                Bitmap::new_zeroed(serie.len())
+            } else if let Some(v) = serie.validity() {
+                v.clone()
+            } else {
+                continue;
+            };
+            validity = Some(if let Some(acc) = validity {
+                &acc & &col_validity
+            } else {
+                col_validity
+            });
+        }
+        if let Some(v) = validity {
+            self.filter(&BooleanArray::new(DataType::Boolean, v, None))
+        } else {
+            Ok(self.clone())
+        }
+    }
+    /// Sort rows in-place by a column
+    ///
+    /// Nulls are placed last for ascending and first for descending ordering
+    pub fn sort_by(&mut self, name: &str, ascending: bool) -> Result<(), Error> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        let pos = self.column_index(name)?;
+        let options = compute::sort::SortOptions {
+            descending: !ascending,
+            nulls_first: !ascending,
+        };
+        let indices =
+            compute::sort::sort_to_indices::<i32>(self.data[pos].as_ref(), &options, None)?;
+        self.take_in_place(&indices)
+    }
+    /// Sort rows in-place by multiple columns, the boolean is ascending flag
+    ///
+    /// Nulls are placed last for ascending and first for descending ordering
+    #[inline]
+    pub fn sort_by_columns(&mut self, keys: &[(&str, bool)]) -> Result<(), Error> {
+        self.sort_by_columns_with_options(keys, &SortByOptions::default())
+    }
+    /// Sort rows in-place by multiple columns with custom options
+    pub fn sort_by_columns_with_options(
+        &mut self,
+        keys: &[(&str, bool)],
+        options: &SortByOptions,
+    ) -> Result<(), Error> {
+        let mut columns = Vec::with_capacity(keys.len());
+        for (name, ascending) in keys {
+            columns.push(compute::sort::SortColumn {
+                values: self.data[self.column_index(name)?].as_ref(),
+                options: Some(compute::sort::SortOptions {
+                    descending: !ascending,
+                    nulls_first: options.nulls_first.unwrap_or(!ascending),
+                }),
+            });
+        }
+        if columns.is_empty() || self.rows() == Some(0) {
+            return Ok(());
+        }
+        let indices = compute::sort::lexsort_to_indices::<i32>(&columns, None)?;
+        self.take_in_place(&indices)
+    }
+    /// Remove consecutive rows with equal values in the column, returns the new row count
+    ///
+    /// Nulls are never equal to each other
+    pub fn dedup_by(&mut self, name: &str) -> Result<usize, Error> {
+        let serie = self.data[self.column_index(name)?].as_ref();
+        let keep: Vec<bool> = (0..serie.len())
+            .map(|i| i == 0 || !values_eq(serie, i - 1, i))
+            .collect();
+        *self = self.filter(&BooleanArray::from_slice(keep))?;
+        Ok(self.rows().unwrap_or_default())
+    }
+    /// Remove consecutive duplicate rows, returns the new row count
+    ///
+    /// Nulls are never equal to each other
+    ///
+    /// # Panics
+    ///
+    /// should not panic
+    pub fn dedup_all(&mut self) -> usize {
+        let keep: Vec<bool> = (0..self.rows().unwrap_or_default())
+            .map(|i| {
+                i == 0
+                    || self
+                        .data
+                        .iter()
+                        .any(|serie| !values_eq(serie.as_ref(), i - 1, i))
+            })
+            .collect();
+        *self = self.filter(&BooleanArray::from_slice(keep)).unwrap();
+        self.rows().unwrap_or_default()
+    }
+    fn take_in_place(&mut self, indices: &PrimitiveArray<i32>) -> Result<(), Error> {
+        let data = self
+            .data
+            .iter()
+            .map(|d| compute::take::take(d.as_ref(), indices))
+            .collect::<Result<Vec<Series>, ArrowError>>()?;
+        self.data = data;
+        Ok(())
+    }
     /// Truncate in-place
     pub fn truncate(&mut self, offset: usize, length: usize) -> Result<(), Error> {
         if self.data.is_empty() {
@@ -317,6 +584,27 @@ impl DataFrame {
     pub fn schema(&self) -> Schema {
         Schema::from(self.fields.clone()).with_metadata(self.metadata.clone())
     }
+    /// Stable schema hash (FNV-1a) of column names, data types and nullable flags, the metadata
+    /// is not included
+    pub fn schema_fingerprint(&self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0100_0000_01b3;
+        fn feed(mut hash: u64, bytes: &[u8]) -> u64 {
+            for b in bytes {
+                hash ^= u64::from(*b);
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+            hash
+        }
+        let mut hash = FNV_OFFSET;
+        for field in &self.fields {
+            hash = feed(hash, field.name.as_bytes());
+            hash = feed(hash, &[0]);
+            hash = feed(hash, format!("{:?}", field.data_type()).as_bytes());
+            hash = feed(hash, &[0, u8::from(field.is_nullable), 0]);
+        }
+        hash
+    }
     #[inline]
     pub fn rows(&self) -> Option<usize> {
         self.data.first().map(|v| v.len())
@@ -337,6 +625,19 @@ impl DataFrame {
         }
         size
     }
+    /// Count nulls in a column
+    #[inline]
+    pub fn null_count(&self, name: &str) -> Result<usize, Error> {
+        Ok(self.data[self.column_index(name)?].null_count())
+    }
+    /// Count nulls in all columns
+    pub fn null_counts(&self) -> Vec<(String, usize)> {
+        self.fields
+            .iter()
+            .zip(&self.data)
+            .map(|(field, serie)| (field.name.clone(), serie.null_count()))
+            .collect()
+    }
     /// Get column index
     #[inline]
     pub fn get_column_index(&self, name: &str) -> Option<usize> {
@@ -370,17 +671,37 @@ impl DataFrame {
         (schema, chunk)
     }
     /// Convert into IPC ready-to-send block
+    #[inline]
     pub fn into_ipc_block(self) -> Result<Vec<u8>, ArrowError> {
+        self.into_ipc_block_with_options(WriteOptions::default())
+    }
+    /// Convert into compressed IPC ready-to-send block
+    #[inline]
+    pub fn into_ipc_block_compressed(
+        self,
+        compression: CompressionType,
+    ) -> Result<Vec<u8>, ArrowError> {
+        self.into_ipc_block_with_options(WriteOptions {
+            compression: Some(compression.into()),
+        })
+    }
+    fn into_ipc_block_with_options(self, options: WriteOptions) -> Result<Vec<u8>, ArrowError> {
         let mut buf = Vec::new();
         let schema = Schema::from(self.fields).with_metadata(self.metadata);
         let chunk = Chunk::new(self.data);
-        let mut writer = StreamWriter::new(&mut buf, WriteOptions::default());
+        let mut writer = StreamWriter::new(&mut buf, options);
         writer.start(&schema, None)?;
         writer.write(&chunk, None)?;
         writer.finish()?;
         Ok(buf)
     }
     /// Create a data frame from a complete IPC block
+    ///
+    /// Compressed blocks (see [`DataFrame::into_ipc_block_compressed`]) are decompressed
+    /// automatically, the compression is detected from the IPC stream.
+    ///
+    /// Only the first chunk of the stream is read, use [`DataFrame::from_ipc_block_concat`] or
+    /// [`DataFrame::from_ipc_block_all_chunks`] for multi-chunk streams
     pub fn from_ipc_block(block: &[u8]) -> Result<Self, ArrowError> {
         let mut buf = std::io::Cursor::new(block);
         let meta = arrow2::io::ipc::read::read_stream_metadata(&mut buf)?;
@@ -404,6 +725,140 @@ impl DataFrame {
         df.metadata = metadata;
         Ok(df)
     }
+    /// Create a data frame from a complete IPC block, compressed or not, and return it with the
+    /// schema metadata
+    ///
+    /// The compression is detected from the IPC stream automatically, see
+    /// [`DataFrame::from_ipc_block`]
+    pub fn from_ipc_block_compressed(block: &[u8]) -> Result<(Self, Metadata), ArrowError> {
+        let df = Self::from_ipc_block(block)?;
+        let metadata = df.metadata.clone();
+        Ok((df, metadata))
+    }
+    /// Read the schema and its metadata from an IPC block header, the arrays are not decoded
+    pub fn ipc_block_schema(block: &[u8]) -> Result<(Schema, Metadata), ArrowError> {
+        let mut buf = std::io::Cursor::new(block);
+        let meta = arrow2::io::ipc::read::read_stream_metadata(&mut buf)?;
+        let metadata = meta.schema.metadata.clone();
+        Ok((meta.schema, metadata))
+    }
+    /// Create data frames from all chunks of a complete IPC block
+    pub fn from_ipc_block_all_chunks(block: &[u8]) -> Result<(Vec<Self>, Metadata), ArrowError> {
+        let mut buf = std::io::Cursor::new(block);
+        let meta = arrow2::io::ipc::read::read_stream_metadata(&mut buf)?;
+        let reader = StreamReader::new(buf, meta, None);
+        let fields = reader.metadata().schema.fields.clone();
+        let metadata = reader.metadata().schema.metadata.clone();
+        let mut result = Vec::new();
+        for state in reader {
+            match state? {
+                StreamState::Waiting => continue,
+                StreamState::Some(chunk) => {
+                    result.push(Self {
+                        fields: fields.clone(),
+                        data: chunk.into_arrays(),
+                        metadata: metadata.clone(),
+                    });
+                }
+            }
+        }
+        Ok((result, metadata))
+    }
+    /// Create a data frame from a complete IPC block, concatenating all chunks
+    pub fn from_ipc_block_concat(block: &[u8]) -> Result<(Self, Metadata), ArrowError> {
+        let (mut dfs, metadata) = Self::from_ipc_block_all_chunks(block)?;
+        if dfs.len() < 2 {
+            let df = dfs.pop().unwrap_or_else(|| {
+                let mut df = DataFrame::new0();
+                df.metadata = metadata.clone();
+                df
+            });
+            return Ok((df, metadata));
+        }
+        let fields = dfs[0].fields.clone();
+        let data = concat_columns(dfs.into_iter().map(|df| df.data).collect())?;
+        let df = Self {
+            fields,
+            data,
+            metadata: metadata.clone(),
+        };
+        Ok((df, metadata))
+    }
+    /// Write the data frame into a file, using IPC file format
+    pub fn write_ipc_file(self, path: &Path) -> Result<(), ArrowError> {
+        let file = std::fs::File::create(path)?;
+        let schema = Schema::from(self.fields).with_metadata(self.metadata);
+        let chunk = Chunk::new(self.data);
+        let mut writer = FileWriter::try_new(file, schema, None, WriteOptions::default())?;
+        writer.write(&chunk, None)?;
+        writer.finish()?;
+        Ok(())
+    }
+    /// Read a data frame from IPC file, all chunks are concatenated
+    #[inline]
+    pub fn read_ipc_file(path: &Path) -> Result<(Self, Metadata), ArrowError> {
+        Self::read_ipc_file_with_projection(path, None)
+    }
+    /// Read the specified columns only from IPC file, all chunks are concatenated
+    ///
+    /// The columns are returned in the requested order, repeated columns are returned once
+    pub fn read_ipc_file_projected(
+        path: &Path,
+        columns: &[&str],
+    ) -> Result<(Self, Metadata), ArrowError> {
+        let mut projection = Vec::with_capacity(columns.len());
+        {
+            let mut file = std::fs::File::open(path)?;
+            let meta = read_file_metadata(&mut file)?;
+            for name in columns {
+                if let Some(pos) = meta.schema.fields.iter().position(|f| f.name == *name) {
+                    projection.push(pos);
+                } else {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "column not found: {}",
+                        name
+                    )));
+                }
+            }
+        }
+        projection.sort_unstable();
+        projection.dedup();
+        let mut names: Vec<&str> = Vec::with_capacity(columns.len());
+        for name in columns {
+            if !names.contains(name) {
+                names.push(name);
+            }
+        }
+        let (mut df, metadata) = Self::read_ipc_file_with_projection(path, Some(projection))?;
+        df.set_ordering(&names);
+        Ok((df, metadata))
+    }
+    fn read_ipc_file_with_projection(
+        path: &Path,
+        projection: Option<Vec<usize>>,
+    ) -> Result<(Self, Metadata), ArrowError> {
+        let mut file = std::fs::File::open(path)?;
+        let meta = read_file_metadata(&mut file)?;
+        let metadata = meta.schema.metadata.clone();
+        let reader = FileReader::new(file, meta, projection, None);
+        let fields = reader.schema().fields.clone();
+        let mut chunks = Vec::new();
+        for chunk in reader {
+            chunks.push(chunk?.into_arrays());
+        }
+        let df = if chunks.is_empty() {
+            let mut df = DataFrame::new0();
+            df.metadata = metadata.clone();
+            df
+        } else {
+            Self {
+                fields,
+                data: concat_columns(chunks)?,
+                metadata: metadata.clone(),
+            }
+        };
+        Ok((df, metadata))
+    }
     /// horizontally join two data frames
     pub fn join(&mut self, other: Self) -> Result<(), Error> {
         if !other.is_empty() {
@@ -427,6 +882,94 @@ impl DataFrame {
         }
         Ok(())
     }
+    /// horizontally concatenate two data frames into a new one
+    pub fn hconcat(&self, other: &DataFrame) -> Result<Self, Error> {
+        if let (Some(rows), Some(other_rows)) = (self.rows(), other.rows()) {
+            if rows != other_rows {
+                return Err(Error::RowsNotMatch);
+            }
+        }
+        for field in &other.fields {
+            if self.get_column_index(&field.name).is_some() {
+                return Err(Error::DuplicateColumn(field.name.clone()));
+            }
+        }
+        let mut fields = Vec::with_capacity(self.fields.len() + other.fields.len());
+        fields.extend(self.fields.iter().cloned());
+        fields.extend(other.fields.iter().cloned());
+        let mut data = Vec::with_capacity(self.data.len() + other.data.len());
+        data.extend(self.data.iter().cloned());
+        data.extend(other.data.iter().cloned());
+        Ok(Self {
+            fields,
+            data,
+            metadata: self.metadata.clone(),
+        })
+    }
+    /// vertically append another data frame with the same schema
+    pub fn append(&mut self, other: &DataFrame) -> Result<(), Error> {
+        if other.is_empty() {
+            return Ok(());
+        }
+        if self.is_empty() {
+            self.fields = other.fields.clone();
+            self.data = other.data.clone();
+            return Ok(());
+        }
+        self.check_same_schema(other)?;
+        let data = self
+            .data
+            .iter()
+            .zip(&other.data)
+            .map(|(a, b)| compute::concatenate::concatenate(&[a.as_ref(), b.as_ref()]))
+            .collect::<Result<Vec<Series>, ArrowError>>()?;
+        self.data = data;
+        Ok(())
+    }
+    /// Returns [`Error::SchemaMismatch`] unless both data frames have the same column names and
+    /// types in the same order
+    pub(crate) fn check_same_schema(&self, other: &DataFrame) -> Result<(), Error> {
+        if self.fields.len() != other.fields.len()
+            || self
+                .fields
+                .iter()
+                .zip(&other.fields)
+                .any(|(a, b)| a.name != b.name || a.data_type != b.data_type)
+        {
+            Err(Error::SchemaMismatch)
+        } else {
+            Ok(())
+        }
+    }
+    /// vertically append another data frame with the same schema, consuming both
+    #[inline]
+    pub fn extend(mut self, other: DataFrame) -> Result<Self, Error> {
+        self.append(&other)?;
+        Ok(self)
+    }
+    /// Create a new data frame with the specified columns in the given order
+    pub fn select(&self, names: &[&str]) -> Result<Self, Error> {
+        let indices = names
+            .iter()
+            .map(|name| self.column_index(name))
+            .collect::<Result<Vec<usize>, Error>>()?;
+        self.select_at(&indices)
+    }
+    /// Create a new data frame with the columns at the specified indices in the given order
+    pub fn select_at(&self, indices: &[usize]) -> Result<Self, Error> {
+        let mut fields = Vec::with_capacity(indices.len());
+        let mut data = Vec::with_capacity(indices.len());
+        for &index in indices {
+            let field = self.fields.get(index).ok_or(Error::OutOfBounds)?;
+            fields.push(field.clone());
+            data.push(self.data[index].clone());
+        }
+        Ok(Self {
+            fields,
+            data,
+            metadata: self.metadata.clone(),
+        })
+    }
     /// Clone series by name
     pub fn clone_series(&self, name: &str) -> Option<(Series, DataType)> {
         self.fields
@@ -468,6 +1011,94 @@ impl DataFrame {
             None
         }
     }
+    /// Duplicate a column under a new name, the new column is added to the end
+    pub fn clone_column(&mut self, src: &str, dest: &str) -> Result<(), Error> {
+        let pos = self.column_index(src)?;
+        if self.get_column_index(dest).is_some() {
+            return Err(Error::DuplicateColumn(dest.to_owned()));
+        }
+        let mut field = self.fields[pos].clone();
+        field.name = dest.to_owned();
+        self.fields.push(field);
+        self.data.push(self.data[pos].clone());
+        Ok(())
+    }
+    /// Add a sequential Int64 index column (0..rows), as the first (prepend = true) or the last
+    /// column
+    ///
+    /// The index is not updated on slicing, so sliced data frames have indices starting from
+    /// the slice offset
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn add_index_column(&mut self, name: &str, prepend: bool) -> Result<(), Error> {
+        if self.get_column_index(name).is_some() {
+            return Err(Error::DuplicateColumn(name.to_owned()));
+        }
+        let rows = self.rows().unwrap_or_default();
+        let index = Int64Array::from_iter_values((0..rows).map(|i| i as i64)).boxed();
+        let field = Field::new(name, DataType::Int64, false);
+        if prepend {
+            self.fields.insert(0, field);
+            self.data.insert(0, index);
+        } else {
+            self.fields.push(field);
+            self.data.push(index);
+        }
+        Ok(())
+    }
+    /// Replace column data, the new series must have the same length and data type
+    pub fn replace_series(&mut self, name: &str, series: Series) -> Result<(), Error> {
+        let pos = self.column_index(name)?;
+        if series.len() != self.data[pos].len() {
+            return Err(Error::RowsNotMatch);
+        }
+        if series.data_type() != self.data[pos].data_type()
+            && series.data_type() != &self.fields[pos].data_type
+        {
+            return Err(Error::TypeMismatch);
+        }
+        self.data[pos] = series;
+        Ok(())
+    }
+    /// Set a single column value to null, the values buffer is kept
+    pub fn set_null_at(&mut self, row: usize, name: &str) -> Result<(), Error> {
+        let pos = self.column_index(name)?;
+        let serie = &self.data[pos];
+        if row >= serie.len() {
+            return Err(Error::OutOfBounds);
+        }
+        if serie.is_null(row) {
+            return Ok(());
+        }
+        let mut validity: MutableBitmap = (0..serie.len()).map(|i| serie.is_valid(i)).collect();
+        validity.set(row, false);
+        self.data[pos] = serie.with_validity(Some(validity.into()));
+        Ok(())
+    }
+    /// Remove column by name
+    pub fn drop_column(&mut self, name: &str) -> Result<(), Error> {
+        let pos = self.column_index(name)?;
+        self.fields.remove(pos);
+        self.data.remove(pos);
+        Ok(())
+    }
+    /// Remove multiple columns by name
+    ///
+    /// If any of the columns is not found, no columns are removed
+    pub fn drop_columns(&mut self, names: &[&str]) -> Result<(), Error> {
+        for name in names {
+            self.column_index(name)?;
+        }
+        let mut pos = 0;
+        while pos < self.fields.len() {
+            if names.contains(&self.fields[pos].name.as_str()) {
+                self.fields.remove(pos);
+                self.data.remove(pos);
+            } else {
+                pos += 1;
+            }
+        }
+        Ok(())
+    }
     /// Get series by name
     pub fn get_series(&self, name: &str) -> Option<(&Series, &DataType)> {
         self.fields
@@ -484,6 +1115,71 @@ impl DataFrame {
             None
         }
     }
+    /// Get Float64 series by name as a typed array
+    #[inline]
+    pub fn get_float64(&self, name: &str) -> Result<&Float64Array, Error> {
+        self.get_typed(name)
+    }
+    /// Get Int64 series by name as a typed array (timestamps are returned as raw values)
+    #[inline]
+    pub fn get_int64(&self, name: &str) -> Result<&Int64Array, Error> {
+        self.get_typed(name)
+    }
+    /// Get Boolean series by name as a typed array
+    #[inline]
+    pub fn get_bool(&self, name: &str) -> Result<&BooleanArray, Error> {
+        self.get_typed(name)
+    }
+    /// Get an iterator over Utf8/LargeUtf8 series values by name
+    pub fn get_utf8_iter(
+        &self,
+        name: &str,
+    ) -> Result<Box<dyn Iterator<Item = Option<&str>> + '_>, Error> {
+        let (series, _) = self
+            .get_series(name)
+            .ok_or_else(|| Error::NotFound(name.to_owned()))?;
+        match series.data_type() {
+            DataType::Utf8 => Ok(Box::new(
+                series
+                    .as_any()
+                    .downcast_ref::<Utf8Array<i32>>()
+                    .ok_or(Error::TypeMismatch)?
+                    .iter(),
+            )),
+            DataType::LargeUtf8 => Ok(Box::new(
+                series
+                    .as_any()
+                    .downcast_ref::<Utf8Array<i64>>()
+                    .ok_or(Error::TypeMismatch)?
+                    .iter(),
+            )),
+            _ => Err(Error::TypeMismatch),
+        }
+    }
+    fn get_typed<A: Array + 'static>(&self, name: &str) -> Result<&A, Error> {
+        let (series, _) = self
+            .get_series(name)
+            .ok_or_else(|| Error::NotFound(name.to_owned()))?;
+        series
+            .as_any()
+            .downcast_ref::<A>()
+            .ok_or(Error::TypeMismatch)
+    }
+    /// Iterate over data frame rows
+    ///
+    /// The iterator is lazy and does not copy any data
+    #[inline]
+    pub fn rows_iter(&self) -> RowIter<'_> {
+        RowIter {
+            df: self,
+            index: 0,
+            rows: self.rows().unwrap_or_default(),
+        }
+    }
+    pub(crate) fn column_index(&self, name: &str) -> Result<usize, Error> {
+        self.get_column_index(name)
+            .ok_or_else(|| Error::NotFound(name.to_owned()))
+    }
     /// Rename column
     pub fn rename(&mut self, name: &str, new_name: &str) -> Result<(), Error> {
         if let Some(field) = self.fields.iter_mut().find(|field| field.name == name) {
@@ -558,6 +1254,59 @@ impl DataFrame {
             Err(Error::OutOfBounds)
         }
     }
+    /// Parse Utf8/LargeUtf8 column values to Boolean
+    ///
+    /// "true", "1", "yes", "on" are parsed as true, "false", "0", "no", "off" as false (case
+    /// insensitive), other values become nulls
+    pub fn parse_bool(&mut self, name: &str) -> Result<(), Error> {
+        let pos = self.column_index(name)?;
+        let dt: Vec<Option<bool>> = self
+            .get_utf8_iter(name)?
+            .map(|v| {
+                v.and_then(|s| {
+                    let s = s.trim();
+                    if ["true", "1", "yes", "on"]
+                        .iter()
+                        .any(|t| s.eq_ignore_ascii_case(t))
+                    {
+                        Some(true)
+                    } else if ["false", "0", "no", "off"]
+                        .iter()
+                        .any(|f| s.eq_ignore_ascii_case(f))
+                    {
+                        Some(false)
+                    } else {
+                        None
+                    }
+                })
+            })
+            .collect();
+        self.data[pos] = BooleanArray::from(dt).boxed();
+        self.fields[pos].data_type = DataType::Boolean;
+        Ok(())
+    }
+    /// Parse Utf8/LargeUtf8 column values to Date32 (days since the Unix epoch), using chrono
+    /// format string
+    ///
+    /// Values which can not be parsed become nulls
+    ///
+    /// # Panics
+    ///
+    /// should not panic
+    pub fn parse_date(&mut self, name: &str, format: &str) -> Result<(), Error> {
+        let pos = self.column_index(name)?;
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        let dt: Vec<Option<i32>> = self
+            .get_utf8_iter(name)?
+            .map(|v| {
+                v.and_then(|s| NaiveDate::parse_from_str(s, format).ok())
+                    .and_then(|d| i32::try_from(d.signed_duration_since(epoch).num_days()).ok())
+            })
+            .collect();
+        self.data[pos] = Int32Array::from(dt).to(DataType::Date32).boxed();
+        self.fields[pos].data_type = DataType::Date32;
+        Ok(())
+    }
     /// Convert to string
     pub fn stringify<T>(&mut self, name: &str) -> Result<(), Error>
     where
@@ -621,6 +1370,254 @@ impl DataFrame {
             Err(Error::OutOfBounds)
         }
     }
+    /// apply a custom function to a Float64 column
+    ///
+    /// The column is replaced only after all values are processed
+    #[inline]
+    pub fn apply_float<F>(&mut self, name: &str, func: F) -> Result<(), Error>
+    where
+        F: Fn(Option<f64>) -> Option<f64>,
+    {
+        self.apply::<F, f64, f64>(name, func)
+    }
+    /// apply a custom function to an Int64 column
+    ///
+    /// The column is replaced only after all values are processed
+    #[inline]
+    pub fn apply_int<F>(&mut self, name: &str, func: F) -> Result<(), Error>
+    where
+        F: Fn(Option<i64>) -> Option<i64>,
+    {
+        self.apply::<F, i64, i64>(name, func)
+    }
+    /// apply a custom function to a Utf8/LargeUtf8 column
+    ///
+    /// The column is replaced only after all values are processed
+    pub fn apply_str<F>(&mut self, name: &str, func: F) -> Result<(), Error>
+    where
+        F: Fn(Option<&str>) -> Option<String>,
+    {
+        let pos = self.column_index(name)?;
+        self.map_utf8_at(pos, func)
+    }
+    /// Convert a Float64 column to Float32
+    ///
+    /// The conversion is lossy: values are rounded to the nearest f32, out-of-range values
+    /// become infinite
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline]
+    pub fn cast_to_float32(&mut self, name: &str) -> Result<(), Error> {
+        self.apply(name, |v: Option<f64>| v.map(|n| n as f32))
+    }
+    /// Convert a Float32 column to Float64
+    #[inline]
+    pub fn cast_to_float64_from_float32(&mut self, name: &str) -> Result<(), Error> {
+        self.apply(name, |v: Option<f32>| v.map(f64::from))
+    }
+    /// Convert an Int64 column to Int32
+    ///
+    /// If any value does not fit into i32, Error::Overflow is returned and the column is not
+    /// modified
+    pub fn cast_to_int32(&mut self, name: &str) -> Result<(), Error> {
+        let pos = self.column_index(name)?;
+        let values: &Int64Array = self.data[pos]
+            .as_any()
+            .downcast_ref()
+            .ok_or(Error::TypeMismatch)?;
+        let dt = values
+            .iter()
+            .map(|v| v.map(|n| i32::try_from(*n)).transpose())
+            .collect::<Result<Vec<Option<i32>>, _>>()
+            .map_err(|_| Error::Overflow)?;
+        self.data[pos] = PrimitiveArray::<i32>::from(dt).boxed();
+        self.fields[pos].data_type = DataType::Int32;
+        Ok(())
+    }
+    /// Convert an Int64 column to Boolean (zero - false, non-zero - true)
+    pub fn cast_to_bool(&mut self, name: &str) -> Result<(), Error> {
+        let pos = self.column_index(name)?;
+        let values: &Int64Array = self.data[pos]
+            .as_any()
+            .downcast_ref()
+            .ok_or(Error::TypeMismatch)?;
+        let dt: Vec<Option<bool>> = values.iter().map(|v| v.map(|n| *n != 0)).collect();
+        self.data[pos] = BooleanArray::from(dt).boxed();
+        self.fields[pos].data_type = DataType::Boolean;
+        Ok(())
+    }
+    /// Convert a Boolean column to Int64 (false - 0, true - 1)
+    pub fn cast_bool_to_int(&mut self, name: &str) -> Result<(), Error> {
+        let pos = self.column_index(name)?;
+        let values: &BooleanArray = self.data[pos]
+            .as_any()
+            .downcast_ref()
+            .ok_or(Error::TypeMismatch)?;
+        let dt: Vec<Option<i64>> = values.iter().map(|v| v.map(i64::from)).collect();
+        self.data[pos] = Int64Array::from(dt).boxed();
+        self.fields[pos].data_type = DataType::Int64;
+        Ok(())
+    }
+    /// Convert a Timestamp column to RFC3339 strings (UTC, microsecond precision)
+    pub fn timestamp_to_rfc3339(&mut self, name: &str) -> Result<(), Error> {
+        let pos = self.column_index(name)?;
+        let time_unit = if let DataType::Timestamp(time_unit, _) = self.fields[pos].data_type {
+            time_unit
+        } else {
+            return Err(Error::TypeMismatch);
+        };
+        let values: &Int64Array = self.data[pos]
+            .as_any()
+            .downcast_ref()
+            .ok_or(Error::TypeMismatch)?;
+        let dt: Vec<Option<String>> = values
+            .iter()
+            .map(|v| {
+                v.and_then(|ts| crate::naive_from_timestamp(*ts, &time_unit))
+                    .map(|t| {
+                        DateTime::<Utc>::from_utc(t, Utc)
+                            .to_rfc3339_opts(SecondsFormat::Micros, true)
+                    })
+            })
+            .collect();
+        self.data[pos] = Utf8Array::<i32>::from(dt).boxed();
+        self.fields[pos].data_type = DataType::Utf8;
+        Ok(())
+    }
+    /// Convert a Timestamp column to Float64 seconds since the epoch
+    #[allow(clippy::cast_precision_loss)]
+    pub fn timestamp_to_float(&mut self, name: &str) -> Result<(), Error> {
+        let pos = self.column_index(name)?;
+        let divisor: i64 = match self.fields[pos].data_type {
+            DataType::Timestamp(TimeUnit::Second, _) => 1,
+            DataType::Timestamp(TimeUnit::Millisecond, _) => 1_000,
+            DataType::Timestamp(TimeUnit::Microsecond, _) => 1_000_000,
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => 1_000_000_000,
+            _ => return Err(Error::TypeMismatch),
+        };
+        let values: &Int64Array = self.data[pos]
+            .as_any()
+            .downcast_ref()
+            .ok_or(Error::TypeMismatch)?;
+        let dt: Vec<Option<f64>> = values
+            .iter()
+            .map(|v| {
+                v.map(|ts| {
+                    ts.div_euclid(divisor) as f64 + ts.rem_euclid(divisor) as f64 / divisor as f64
+                })
+            })
+            .collect();
+        self.data[pos] = Float64Array::from(dt).boxed();
+        self.fields[pos].data_type = DataType::Float64;
+        Ok(())
+    }
+    /// Parse RFC3339 strings to a Timestamp column, returns the number of values which can not
+    /// be parsed and are set to nulls
+    #[inline]
+    pub fn parse_timestamp(
+        &mut self,
+        name: &str,
+        time_unit: TimeUnit,
+        tz: TimeZone,
+    ) -> Result<usize, Error> {
+        self.parse_timestamp_with(name, time_unit, tz, false)
+    }
+    /// Parse RFC3339 strings to a Timestamp column, Error::ParseError is returned on the first
+    /// invalid value and the column is not modified
+    #[inline]
+    pub fn parse_timestamp_strict(
+        &mut self,
+        name: &str,
+        time_unit: TimeUnit,
+        tz: TimeZone,
+    ) -> Result<(), Error> {
+        self.parse_timestamp_with(name, time_unit, tz, true)
+            .map(|_| ())
+    }
+    fn parse_timestamp_with(
+        &mut self,
+        name: &str,
+        time_unit: TimeUnit,
+        tz: TimeZone,
+        strict: bool,
+    ) -> Result<usize, Error> {
+        let mut invalid = 0;
+        let mut dt: Vec<Option<i64>> = Vec::with_capacity(self.rows().unwrap_or_default());
+        for val in self.get_utf8_iter(name)? {
+            if let Some(s) = val {
+                let ts = DateTime::parse_from_rfc3339(s)
+                    .ok()
+                    .and_then(|t| crate::naive_to_timestamp(&t.naive_utc(), &time_unit));
+                if ts.is_none() {
+                    if strict {
+                        return Err(Error::ParseError(format!("invalid timestamp: {}", s)));
+                    }
+                    invalid += 1;
+                }
+                dt.push(ts);
+            } else {
+                dt.push(None);
+            }
+        }
+        let pos = self.column_index(name)?;
+        let data_type = DataType::Timestamp(time_unit, tz.into());
+        self.data[pos] = Int64Array::from(dt).to(data_type.clone()).boxed();
+        self.fields[pos].data_type = data_type;
+        Ok(invalid)
+    }
+    /// Replace nulls in a Float64 column with the value
+    #[inline]
+    pub fn fill_null_float(&mut self, name: &str, value: f64) -> Result<(), Error> {
+        self.fill_null_primitive(name, value)
+    }
+    /// Replace nulls in an Int64 column with the value
+    #[inline]
+    pub fn fill_null_int(&mut self, name: &str, value: i64) -> Result<(), Error> {
+        self.fill_null_primitive(name, value)
+    }
+    /// Replace nulls in a Utf8/LargeUtf8 column with the value
+    pub fn fill_null_str(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        let pos = self.column_index(name)?;
+        self.map_utf8_at(pos, |v| Some(v.unwrap_or(value).to_owned()))?;
+        self.fields[pos].is_nullable = false;
+        Ok(())
+    }
+    fn fill_null_primitive<T: NativeType>(&mut self, name: &str, value: T) -> Result<(), Error> {
+        let pos = self.column_index(name)?;
+        let values: &PrimitiveArray<T> = self.data[pos]
+            .as_any()
+            .downcast_ref()
+            .ok_or(Error::TypeMismatch)?;
+        let arr = PrimitiveArray::<T>::from_vec(
+            values.iter().map(|v| v.copied().unwrap_or(value)).collect(),
+        )
+        .to(values.data_type().clone());
+        self.data[pos] = arr.boxed();
+        self.fields[pos].is_nullable = false;
+        Ok(())
+    }
+    /// Map Utf8/LargeUtf8 series values, keeping the offset type
+    fn map_utf8_at<F>(&mut self, index: usize, func: F) -> Result<(), Error>
+    where
+        F: Fn(Option<&str>) -> Option<String>,
+    {
+        macro_rules! map_utf8 {
+            ($offset: ty) => {{
+                let values: &Utf8Array<$offset> = self.data[index]
+                    .as_any()
+                    .downcast_ref()
+                    .ok_or(Error::TypeMismatch)?;
+                let dt: Vec<Option<String>> = values.iter().map(&func).collect();
+                Utf8Array::<$offset>::from(dt).boxed()
+            }};
+        }
+        let arr = match self.data.get(index).ok_or(Error::OutOfBounds)?.data_type() {
+            DataType::Utf8 => map_utf8!(i32),
+            DataType::LargeUtf8 => map_utf8!(i64),
+            _ => return Err(Error::TypeMismatch),
+        };
+        self.data[index] = arr;
+        Ok(())
+    }
     /// Set field name by index
     pub fn set_name_at(&mut self, index: usize, new_name: &str) -> Result<(), Error> {
         if let Some(field) = self.fields.get_mut(index) {
@@ -724,6 +1721,16 @@ impl DataFrame {
             Err(Error::OutOfBounds)
         }
     }
+    /// Remove all field meta data
+    pub fn clear_col_metadata(&mut self, name: &str) -> Result<(), Error> {
+        self.col_metadata_mut(name)?.clear();
+        Ok(())
+    }
+    /// Remove all field meta data by index
+    pub fn clear_col_metadata_at(&mut self, index: usize) -> Result<(), Error> {
+        self.col_metadata_mut_at(index)?.clear();
+        Ok(())
+    }
     pub fn add<T>(&mut self, name: &str, value: T) -> Result<(), Error>
     where
         T: NativeType + Add,
@@ -990,6 +1997,246 @@ impl DataFrame {
     }
 }
 
+/// Compare two values of a series, nulls are never equal
+fn values_eq(serie: &dyn Array, a: usize, b: usize) -> bool {
+    if serie.is_null(a) || serie.is_null(b) {
+        return false;
+    }
+    macro_rules! cmp {
+        ($kind: ty) => {
+            if let Some(arr) = serie.as_any().downcast_ref::<$kind>() {
+                return arr.value(a) == arr.value(b);
+            }
+        };
+    }
+    cmp!(PrimitiveArray<f64>);
+    cmp!(PrimitiveArray<i64>);
+    cmp!(PrimitiveArray<f32>);
+    cmp!(PrimitiveArray<i32>);
+    cmp!(PrimitiveArray<i16>);
+    cmp!(PrimitiveArray<i8>);
+    cmp!(PrimitiveArray<u64>);
+    cmp!(PrimitiveArray<u32>);
+    cmp!(PrimitiveArray<u16>);
+    cmp!(PrimitiveArray<u8>);
+    cmp!(BooleanArray);
+    cmp!(Utf8Array<i32>);
+    cmp!(Utf8Array<i64>);
+    arrow2::array::equal(serie.sliced(a, 1).as_ref(), serie.sliced(b, 1).as_ref())
+}
+
+/// IPC block compression
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CompressionType {
+    Lz4,
+    Zstd,
+}
+
+impl From<CompressionType> for Compression {
+    fn from(compression: CompressionType) -> Self {
+        match compression {
+            CompressionType::Lz4 => Compression::LZ4,
+            CompressionType::Zstd => Compression::ZSTD,
+        }
+    }
+}
+
+/// Data frames are equal if column names, data types, nullable flags and all values (including
+/// nulls) are equal, metadata is not compared
+///
+/// Nulls are equal to each other regardless of the masked values, NaNs are never equal (the
+/// same as for f64), so a frame with NaN values is not equal to itself
+impl PartialEq for DataFrame {
+    fn eq(&self, other: &Self) -> bool {
+        self.rows() == other.rows()
+            && self.fields.len() == other.fields.len()
+            && self.fields.iter().zip(&other.fields).all(|(a, b)| {
+                a.name == b.name && a.data_type() == b.data_type() && a.is_nullable == b.is_nullable
+            })
+            && self
+                .data
+                .iter()
+                .zip(&other.data)
+                .all(|(a, b)| arrow2::array::equal(a.as_ref(), b.as_ref()))
+    }
+}
+
+const DISPLAY_MAX_ROWS: usize = 10;
+const DISPLAY_MAX_STR: usize = 20;
+const DISPLAY_MAX_WIDTH: usize = 30;
+
+fn clip_str(s: &str, max: usize) -> String {
+    if s.chars().count() > max {
+        let mut clipped: String = s.chars().take(max.saturating_sub(1)).collect();
+        clipped.push('…');
+        clipped
+    } else {
+        s.to_owned()
+    }
+}
+
+impl fmt::Display for DataFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.data.is_empty() {
+            return writeln!(f, "(empty)");
+        }
+        let rows = self.rows().unwrap_or_default();
+        let shown = rows.min(DISPLAY_MAX_ROWS);
+        let mut columns: Vec<Vec<String>> = Vec::with_capacity(self.data.len());
+        for (field, serie) in self.fields.iter().zip(&self.data) {
+            let display = arrow2::array::get_display(serie.as_ref(), "NULL");
+            let is_str = matches!(field.data_type(), DataType::Utf8 | DataType::LargeUtf8);
+            let mut column = Vec::with_capacity(shown + 2);
+            column.push(field.name.clone());
+            column.push(format!("{:?}", field.data_type()));
+            for i in 0..shown {
+                let mut value = String::new();
+                if serie.is_valid(i) {
+                    display(&mut value, i)?;
+                } else {
+                    value.push_str("NULL");
+                }
+                if is_str {
+                    value = clip_str(&value, DISPLAY_MAX_STR);
+                }
+                column.push(value);
+            }
+            columns.push(column);
+        }
+        let widths: Vec<usize> = columns
+            .iter()
+            .map(|c| {
+                c.iter()
+                    .map(|v| v.chars().count())
+                    .max()
+                    .unwrap_or_default()
+                    .min(DISPLAY_MAX_WIDTH)
+            })
+            .collect();
+        for line in 0..shown + 2 {
+            if line == 2 {
+                let sep: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+                writeln!(f, "{}", sep.join("-+-"))?;
+            }
+            for (i, (column, width)) in columns.iter().zip(&widths).enumerate() {
+                if i > 0 {
+                    write!(f, " | ")?;
+                }
+                write!(
+                    f,
+                    "{:<width$}",
+                    clip_str(&column[line], *width),
+                    width = width
+                )?;
+            }
+            writeln!(f)?;
+        }
+        if rows > shown {
+            writeln!(f, "... {} more rows", rows - shown)?;
+        }
+        Ok(())
+    }
+}
+
+fn concat_columns(mut chunks: Vec<Vec<Series>>) -> Result<Vec<Series>, ArrowError> {
+    if chunks.len() == 1 {
+        return Ok(chunks.swap_remove(0));
+    }
+    let cols = chunks.first().map_or(0, Vec::len);
+    let mut data = Vec::with_capacity(cols);
+    for i in 0..cols {
+        let arrays: Vec<&dyn Array> = chunks.iter().map(|c| c[i].as_ref()).collect();
+        data.push(compute::concatenate::concatenate(&arrays)?);
+    }
+    Ok(data)
+}
+
+/// Multi-column sorting options
+#[derive(Debug, Clone, Default)]
+pub struct SortByOptions {
+    /// place nulls first (true) or last (false), if not set, nulls are placed last for
+    /// ascending and first for descending columns (SQL default)
+    pub nulls_first: Option<bool>,
+}
+
+/// A single data frame row, returned by [`RowIter`]
+pub struct Row<'a> {
+    df: &'a DataFrame,
+    index: usize,
+}
+
+impl<'a> Row<'a> {
+    /// Row index in the data frame
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    /// Get Float64 value, None if null, the column is not found or has a different type
+    pub fn get_float64(&self, name: &str) -> Option<f64> {
+        let arr = self.typed::<Float64Array>(name)?;
+        self.is_valid(arr).then(|| arr.value(self.index))
+    }
+    /// Get Int64 value, None if null, the column is not found or has a different type
+    pub fn get_int64(&self, name: &str) -> Option<i64> {
+        let arr = self.typed::<Int64Array>(name)?;
+        self.is_valid(arr).then(|| arr.value(self.index))
+    }
+    /// Get Boolean value, None if null, the column is not found or has a different type
+    pub fn get_bool(&self, name: &str) -> Option<bool> {
+        let arr = self.typed::<BooleanArray>(name)?;
+        self.is_valid(arr).then(|| arr.value(self.index))
+    }
+    /// Get Utf8/LargeUtf8 value, None if null, the column is not found or has a different type
+    pub fn get_utf8(&self, name: &str) -> Option<&'a str> {
+        if let Some(arr) = self.typed::<Utf8Array<i32>>(name) {
+            self.is_valid(arr).then(|| arr.value(self.index))
+        } else {
+            let arr = self.typed::<Utf8Array<i64>>(name)?;
+            self.is_valid(arr).then(|| arr.value(self.index))
+        }
+    }
+    /// The column array, None if the column is not found, its data is missing (the schema has
+    /// more fields than arrays) or it has a different type
+    fn typed<A: Array + 'static>(&self, name: &str) -> Option<&'a A> {
+        let pos = self.df.get_column_index(name)?;
+        self.df.data().get(pos)?.as_any().downcast_ref::<A>()
+    }
+    #[inline]
+    fn is_valid(&self, arr: &dyn Array) -> bool {
+        self.index < arr.len() && arr.is_valid(self.index)
+    }
+}
+
+/// Data frame row iterator, created with [`DataFrame::rows_iter`]
+pub struct RowIter<'a> {
+    df: &'a DataFrame,
+    index: usize,
+    rows: usize,
+}
+
+impl<'a> Iterator for RowIter<'a> {
+    type Item = Row<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.rows {
+            let row = Row {
+                df: self.df,
+                index: self.index,
+            };
+            self.index += 1;
+            Some(row)
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.rows - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for RowIter<'a> {}
+
 impl From<DataFrame> for Chunk<Box<dyn Array>> {
     #[inline]
     fn from(df: DataFrame) -> Self {
@@ -1053,3 +2300,1016 @@ impl From<polars::frame::DataFrame> for DataFrame {
         df
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn df_of(columns: Vec<(&str, Series)>) -> DataFrame {
+        let mut df = DataFrame::new0();
+        for (name, serie) in columns {
+            df.add_series0(name, serie).unwrap();
+        }
+        df
+    }
+
+    #[test]
+    fn test_eq() {
+        let df = df_of(vec![
+            (
+                "a",
+                Float64Array::from(vec![Some(1.0), None, Some(3.0)]).boxed(),
+            ),
+            (
+                "b",
+                Int64Array::from(vec![Some(1), Some(2), Some(3)]).boxed(),
+            ),
+        ]);
+        assert!(df == df.clone());
+        let mut other = df.clone();
+        other.set_metadata_field("key", "value");
+        assert!(df == other);
+        // differing by one null
+        let other = df_of(vec![
+            (
+                "a",
+                Float64Array::from(vec![Some(1.0), None, Some(3.0)]).boxed(),
+            ),
+            ("b", Int64Array::from(vec![Some(1), None, Some(3)]).boxed()),
+        ]);
+        assert!(df != other);
+        // differing by column order
+        let other = df_of(vec![
+            (
+                "b",
+                Int64Array::from(vec![Some(1), Some(2), Some(3)]).boxed(),
+            ),
+            (
+                "a",
+                Float64Array::from(vec![Some(1.0), None, Some(3.0)]).boxed(),
+            ),
+        ]);
+        assert!(df != other);
+        // differing by type
+        let other = df_of(vec![
+            (
+                "a",
+                Float64Array::from(vec![Some(1.0), None, Some(3.0)]).boxed(),
+            ),
+            (
+                "b",
+                Int32Array::from(vec![Some(1), Some(2), Some(3)]).boxed(),
+            ),
+        ]);
+        assert!(df != other);
+    }
+
+    #[test]
+    fn test_eq_nulls_nan() {
+        // the masked values of nulls are not compared
+        let a = df_of(vec![(
+            "a",
+            Float64Array::from_vec(vec![1.0, 2.0])
+                .with_validity(Some(Bitmap::from([true, false])))
+                .boxed(),
+        )]);
+        let b = df_of(vec![(
+            "a",
+            Float64Array::from_vec(vec![1.0, 5.0])
+                .with_validity(Some(Bitmap::from([true, false])))
+                .boxed(),
+        )]);
+        assert!(a == b);
+        let nan = df_of(vec![("a", Float64Array::from_vec(vec![f64::NAN]).boxed())]);
+        assert!(nan != nan.clone());
+    }
+
+    #[test]
+    fn test_typed_accessors() {
+        let df = df_of(vec![
+            ("f", Float64Array::from(vec![Some(1.5), None]).boxed()),
+            ("i", Int64Array::from(vec![Some(1), Some(2)]).boxed()),
+            ("b", BooleanArray::from(vec![Some(true), None]).boxed()),
+            ("s", Utf8Array::<i64>::from(vec![Some("x"), None]).boxed()),
+        ]);
+        let values = df.get_float64("f").unwrap();
+        assert_eq!(values.value(0), 1.5);
+        assert!(values.is_null(1));
+        assert_eq!(df.get_int64("i").unwrap().values().as_slice(), &[1, 2]);
+        assert_eq!(df.get_bool("b").unwrap().get(0), Some(true));
+        assert_eq!(
+            df.get_utf8_iter("s").unwrap().collect::<Vec<_>>(),
+            vec![Some("x"), None]
+        );
+        assert!(matches!(df.get_float64("i"), Err(Error::TypeMismatch)));
+        assert!(matches!(df.get_int64("f"), Err(Error::TypeMismatch)));
+        assert!(matches!(df.get_bool("s"), Err(Error::TypeMismatch)));
+        assert!(matches!(df.get_utf8_iter("f"), Err(Error::TypeMismatch)));
+        assert!(matches!(df.get_float64("x"), Err(Error::NotFound(n)) if n == "x"));
+        assert!(matches!(df.get_utf8_iter("x"), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn test_filter() {
+        let df = df_of(vec![
+            ("a", Int64Array::from_vec(vec![1, 2, 3]).boxed()),
+            ("b", Utf8Array::<i32>::from_slice(["x", "y", "z"]).boxed()),
+        ]);
+        let empty = df.filter(&BooleanArray::from_slice([false; 3])).unwrap();
+        assert_eq!(empty.rows(), Some(0));
+        assert_eq!(empty.names(), vec!["a", "b"]);
+        let mask = BooleanArray::from(vec![Some(true), None, Some(true)]);
+        let filtered = df.filter(&mask).unwrap();
+        assert_eq!(
+            filtered.get_int64("a").unwrap().values().as_slice(),
+            &[1, 3]
+        );
+        assert_eq!(
+            filtered.get_utf8_iter("b").unwrap().collect::<Vec<_>>(),
+            vec![Some("x"), Some("z")]
+        );
+        assert!(matches!(
+            df.filter(&BooleanArray::from_slice([true; 2])),
+            Err(Error::RowsNotMatch)
+        ));
+    }
+
+    #[test]
+    fn test_fill_null() {
+        let mut df = df_of(vec![
+            ("f", Float64Array::from(vec![Some(1.5), None]).boxed()),
+            ("i", Int64Array::from(vec![None, Some(2)]).boxed()),
+            ("s", Utf8Array::<i32>::from(vec![None, Some("x")]).boxed()),
+        ]);
+        df.fill_null_float("f", 0.5).unwrap();
+        df.fill_null_int("i", -1).unwrap();
+        df.fill_null_str("s", "-").unwrap();
+        for name in ["f", "i", "s"] {
+            assert_eq!(df.null_count(name).unwrap(), 0);
+        }
+        assert!(df.fields().iter().all(|f| !f.is_nullable));
+        assert_eq!(
+            df.get_float64("f").unwrap().values().as_slice(),
+            &[1.5, 0.5]
+        );
+        assert_eq!(df.get_int64("i").unwrap().values().as_slice(), &[-1, 2]);
+        assert_eq!(
+            df.get_utf8_iter("s").unwrap().collect::<Vec<_>>(),
+            vec![Some("-"), Some("x")]
+        );
+        assert!(matches!(
+            df.fill_null_float("i", 0.0),
+            Err(Error::TypeMismatch)
+        ));
+        assert!(matches!(df.fill_null_int("f", 0), Err(Error::TypeMismatch)));
+        assert!(matches!(
+            df.fill_null_str("f", ""),
+            Err(Error::TypeMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_drop_nulls() {
+        let df = df_of(vec![
+            ("a", Int64Array::from_vec(vec![1, 2, 3]).boxed()),
+            ("b", Float64Array::from_vec(vec![1.0, 2.0, 3.0]).boxed()),
+        ]);
+        assert!(df.drop_nulls() == df);
+        let df = df_of(vec![
+            ("a", Int64Array::from(vec![Some(1), None, Some(3)]).boxed()),
+            (
+                "b",
+                Float64Array::from(vec![None, Some(2.0), Some(3.0)]).boxed(),
+            ),
+            ("c", Int64Array::from(vec![None, None, Some(0)]).boxed()),
+        ]);
+        let dropped = df.drop_nulls();
+        assert_eq!(dropped.rows(), Some(1));
+        assert_eq!(dropped.get_int64("a").unwrap().values().as_slice(), &[3]);
+        let df = df_of(vec![
+            ("a", Int64Array::from(vec![Some(1), None]).boxed()),
+            ("b", Float64Array::from(vec![None, Some(2.0)]).boxed()),
+        ]);
+        let dropped = df.drop_nulls();
+        assert_eq!(dropped.rows(), Some(0));
+        assert_eq!(dropped.names(), vec!["a", "b"]);
+        let dropped = df.drop_nulls_in(&["a"]).unwrap();
+        assert_eq!(dropped.rows(), Some(1));
+        assert!(dropped.get_float64("b").unwrap().is_null(0));
+        assert!(df.drop_nulls_in(&[]).unwrap() == df);
+        assert!(matches!(df.drop_nulls_in(&["x"]), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn test_sort_by_columns() {
+        let mut df = df_of(vec![
+            ("a", Int64Array::from_vec(vec![1, 1, 0, 1, 1]).boxed()),
+            (
+                "b",
+                Utf8Array::<i32>::from_slice(["x", "y", "z", "x", "x"]).boxed(),
+            ),
+            (
+                "c",
+                Float64Array::from(vec![Some(2.0), Some(0.0), Some(9.0), None, Some(1.0)]).boxed(),
+            ),
+            ("id", Int64Array::from_vec(vec![0, 1, 2, 3, 4]).boxed()),
+        ]);
+        let mut desc = df.clone();
+        df.sort_by_columns(&[("a", true), ("b", false), ("c", true)])
+            .unwrap();
+        assert_eq!(
+            df.get_int64("id").unwrap().values().as_slice(),
+            &[2, 1, 4, 0, 3]
+        );
+        desc.sort_by_columns(&[("a", false), ("b", true), ("c", false)])
+            .unwrap();
+        assert_eq!(
+            desc.get_int64("id").unwrap().values().as_slice(),
+            &[3, 0, 4, 1, 2]
+        );
+        let mut first = df.clone();
+        first
+            .sort_by_columns_with_options(
+                &[("a", true), ("b", false), ("c", true)],
+                &SortByOptions {
+                    nulls_first: Some(true),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            first.get_int64("id").unwrap().values().as_slice(),
+            &[2, 1, 3, 4, 0]
+        );
+        assert!(matches!(
+            df.sort_by_columns(&[("a", true), ("x", true)]),
+            Err(Error::NotFound(n)) if n == "x"
+        ));
+    }
+    #[test]
+    fn test_dedup() {
+        let mut df = df_of(vec![("a", Int64Array::from_vec(vec![1, 2, 3]).boxed())]);
+        assert_eq!(df.dedup_by("a").unwrap(), 3);
+        let mut df = df_of(vec![
+            ("a", Int64Array::from_vec(vec![5, 5, 5]).boxed()),
+            ("b", Int64Array::from_vec(vec![1, 1, 2]).boxed()),
+        ]);
+        assert_eq!(df.clone().dedup_by("a").unwrap(), 1);
+        assert_eq!(df.dedup_all(), 2);
+        assert_eq!(df.get_int64("b").unwrap().values().as_slice(), &[1, 2]);
+        let mut df = df_of(vec![(
+            "a",
+            Float64Array::from(vec![
+                Some(1.0),
+                Some(1.0),
+                None,
+                None,
+                Some(f64::NAN),
+                Some(f64::NAN),
+            ])
+            .boxed(),
+        )]);
+        assert_eq!(df.dedup_by("a").unwrap(), 5);
+        assert_eq!(df.null_count("a").unwrap(), 2);
+        assert!(matches!(df.dedup_by("x"), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn test_cast_float32() {
+        let values = vec![Some(1.1), None, Some(-123.456_789), Some(1e-3)];
+        let mut df = df_of(vec![("a", Float64Array::from(values.clone()).boxed())]);
+        df.cast_to_float32("a").unwrap();
+        assert_eq!(df.fields()[0].data_type, DataType::Float32);
+        assert_eq!(df.null_count("a").unwrap(), 1);
+        assert!(matches!(df.get_float64("a"), Err(Error::TypeMismatch)));
+        df.cast_to_float64_from_float32("a").unwrap();
+        assert_eq!(df.fields()[0].data_type, DataType::Float64);
+        for (v, orig) in df.get_float64("a").unwrap().iter().zip(values) {
+            if let Some(orig) = orig {
+                assert!((v.unwrap() - orig).abs() < 1e-6 * orig.abs().max(1.0));
+            } else {
+                assert!(v.is_none());
+            }
+        }
+        assert!(matches!(
+            df.cast_to_float64_from_float32("a"),
+            Err(Error::TypeMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_cast_int32() {
+        let mut df = df_of(vec![(
+            "a",
+            Int64Array::from(vec![
+                Some(i64::from(i32::MIN)),
+                None,
+                Some(i64::from(i32::MAX)),
+            ])
+            .boxed(),
+        )]);
+        df.cast_to_int32("a").unwrap();
+        assert_eq!(df.fields()[0].data_type, DataType::Int32);
+        let values: &PrimitiveArray<i32> = df.data()[0].as_any().downcast_ref().unwrap();
+        assert_eq!(
+            values.iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(i32::MIN), None, Some(i32::MAX)]
+        );
+        let mut df = df_of(vec![(
+            "a",
+            Int64Array::from(vec![Some(1), None, Some(i64::from(i32::MAX) + 1)]).boxed(),
+        )]);
+        let orig = df.clone();
+        assert!(matches!(df.cast_to_int32("a"), Err(Error::Overflow)));
+        assert!(df == orig);
+        assert_eq!(df.fields()[0].data_type, DataType::Int64);
+    }
+
+    #[test]
+    fn test_cast_bool() {
+        let mut df = df_of(vec![(
+            "a",
+            Int64Array::from(vec![Some(0), Some(-5), None, Some(1)]).boxed(),
+        )]);
+        df.cast_to_bool("a").unwrap();
+        assert_eq!(df.fields()[0].data_type, DataType::Boolean);
+        assert_eq!(
+            df.get_bool("a").unwrap().iter().collect::<Vec<_>>(),
+            vec![Some(false), Some(true), None, Some(true)]
+        );
+        let orig = df.clone();
+        df.cast_bool_to_int("a").unwrap();
+        assert_eq!(
+            df.get_int64("a")
+                .unwrap()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(0), Some(1), None, Some(1)]
+        );
+        df.cast_to_bool("a").unwrap();
+        assert!(df == orig);
+        assert!(matches!(df.cast_bool_to_int("x"), Err(Error::NotFound(_))));
+        df.cast_bool_to_int("a").unwrap();
+        assert!(matches!(df.cast_bool_to_int("a"), Err(Error::TypeMismatch)));
+    }
+
+    #[test]
+    fn test_new_timeseries_from_i64() {
+        let ts = vec![1_682_000_000_123_456_789, 1_682_000_000_123_456_790, -1];
+        let df = DataFrame::new_timeseries_from_i64(
+            ts.clone(),
+            None,
+            TimeZone::Custom("UTC".to_owned()),
+            TimeUnit::Nanosecond,
+        );
+        assert_eq!(df.names(), vec!["time"]);
+        assert_eq!(
+            df.fields()[0].data_type,
+            DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".to_owned()))
+        );
+        assert_eq!(
+            df.get_int64("time").unwrap().values().as_slice(),
+            ts.as_slice()
+        );
+        let df =
+            DataFrame::new_timeseries_from_i64(vec![], Some(1), TimeZone::No, TimeUnit::Second);
+        assert_eq!(df.rows(), Some(0));
+        assert_eq!(
+            df.fields()[0].data_type,
+            DataType::Timestamp(TimeUnit::Second, None)
+        );
+    }
+
+    #[test]
+    fn test_timestamp_to_float() {
+        let ts = vec![1_682_000_000.25, 0.5, 1_682_000_123.123_456];
+        for time_unit in [
+            TimeUnit::Millisecond,
+            TimeUnit::Microsecond,
+            TimeUnit::Nanosecond,
+        ] {
+            let mut df =
+                DataFrame::new_timeseries_from_float(ts.clone(), None, TimeZone::No, time_unit);
+            df.timestamp_to_float("time").unwrap();
+            assert_eq!(df.fields()[0].data_type, DataType::Float64);
+            for (v, orig) in df.get_float64("time").unwrap().values_iter().zip(&ts) {
+                assert!((v - orig).abs() < 1e-3, "{} != {}", v, orig);
+            }
+        }
+        let mut df = DataFrame::new_timeseries_from_i64(
+            vec![-1_500, 1_500],
+            None,
+            TimeZone::No,
+            TimeUnit::Millisecond,
+        );
+        df.timestamp_to_float("time").unwrap();
+        assert_eq!(
+            df.get_float64("time").unwrap().values().as_slice(),
+            &[-1.5, 1.5]
+        );
+        assert!(matches!(
+            df.timestamp_to_float("time"),
+            Err(Error::TypeMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_select() {
+        let df = df_of(vec![
+            ("a", Int64Array::from_vec(vec![1, 2]).boxed()),
+            ("b", Float64Array::from_vec(vec![1.0, 2.0]).boxed()),
+            ("c", BooleanArray::from_slice([true, false]).boxed()),
+        ]);
+        let selected = df.select(&["c", "a"]).unwrap();
+        assert_eq!(selected.names(), vec!["c", "a"]);
+        assert_eq!(selected.fields()[0].data_type, DataType::Boolean);
+        assert_eq!(selected.rows(), df.rows());
+        let selected = df.select_at(&[1, 0, 1]).unwrap();
+        assert_eq!(selected.names(), vec!["b", "a", "b"]);
+        assert!(matches!(df.select(&["a", "x", "y"]), Err(Error::NotFound(n)) if n == "x"));
+        assert!(matches!(df.select_at(&[3]), Err(Error::OutOfBounds)));
+    }
+
+    #[test]
+    fn test_head_tail() {
+        let df = df_of(vec![
+            ("a", Int64Array::from_vec(vec![1, 2, 3]).boxed()),
+            ("b", Float64Array::from_vec(vec![1.0, 2.0, 3.0]).boxed()),
+        ]);
+        assert_eq!(
+            df.head(2).get_int64("a").unwrap().values().as_slice(),
+            &[1, 2]
+        );
+        assert_eq!(
+            df.tail(2).get_int64("a").unwrap().values().as_slice(),
+            &[2, 3]
+        );
+        assert!(df.head(3) == df);
+        assert!(df.tail(10) == df);
+        for empty in [df.head(0), df.tail(0)] {
+            assert_eq!(empty.rows(), Some(0));
+            assert_eq!(empty.names(), vec!["a", "b"]);
+        }
+        assert_eq!(df.head_chunk(1).len(), 1);
+        assert_eq!(df.tail_chunk(0).len(), 0);
+        assert_eq!(df.tail_chunk(5).arrays().len(), 2);
+        assert_eq!(DataFrame::new0().head(1).rows(), None);
+    }
+
+    #[test]
+    fn test_take() {
+        let df = df_of(vec![
+            ("a", Int64Array::from_vec(vec![1, 2, 3]).boxed()),
+            ("b", Utf8Array::<i32>::from_slice(["x", "y", "z"]).boxed()),
+        ]);
+        let taken = df.take(&Int32Array::from_slice([2, 1, 0])).unwrap();
+        assert_eq!(
+            taken.get_int64("a").unwrap().values().as_slice(),
+            &[3, 2, 1]
+        );
+        assert_eq!(
+            taken.get_utf8_iter("b").unwrap().collect::<Vec<_>>(),
+            vec![Some("z"), Some("y"), Some("x")]
+        );
+        let taken = df
+            .take(&Int32Array::from(vec![Some(1), None, Some(1)]))
+            .unwrap();
+        assert_eq!(taken.rows(), Some(3));
+        assert_eq!(
+            taken.get_utf8_iter("b").unwrap().collect::<Vec<_>>(),
+            vec![Some("y"), None, Some("y")]
+        );
+        assert_eq!(taken.null_count("a").unwrap(), 1);
+        assert!(matches!(
+            df.take(&Int32Array::from_slice([3])),
+            Err(Error::OutOfBounds)
+        ));
+        assert!(matches!(
+            df.take(&Int32Array::from_slice([-1])),
+            Err(Error::OutOfBounds)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sample() {
+        let df = df_of(vec![("a", Int64Array::from_iter_values(0..100).boxed())]);
+        let sample = df.sample(30, Some(42)).unwrap();
+        assert!(sample == df.sample(30, Some(42)).unwrap());
+        assert_eq!(sample.rows(), Some(30));
+        let mut values = sample.get_int64("a").unwrap().values().to_vec();
+        values.sort_unstable();
+        values.dedup();
+        assert_eq!(values.len(), 30);
+        assert!(values.iter().all(|v| (0..100).contains(v)));
+        assert_eq!(df.sample(100, None).unwrap().rows(), Some(100));
+        assert_eq!(df.sample(0, None).unwrap().rows(), Some(0));
+        assert!(matches!(df.sample(101, Some(1)), Err(Error::OutOfBounds)));
+    }
+
+    #[test]
+    fn test_replace_series() {
+        let mut df = df_of(vec![
+            ("a", Float64Array::from_vec(vec![1.0, 2.0]).boxed()),
+            ("b", Int64Array::from_vec(vec![1, 2]).boxed()),
+        ]);
+        df.replace_series("a", Float64Array::from_vec(vec![3.0, 4.0]).boxed())
+            .unwrap();
+        assert_eq!(
+            df.get_float64("a").unwrap().values().as_slice(),
+            &[3.0, 4.0]
+        );
+        assert_eq!(df.names(), vec!["a", "b"]);
+        assert!(matches!(
+            df.replace_series("a", Float64Array::from_vec(vec![1.0]).boxed()),
+            Err(Error::RowsNotMatch)
+        ));
+        assert!(matches!(
+            df.replace_series("a", Int64Array::from_vec(vec![1, 2]).boxed()),
+            Err(Error::TypeMismatch)
+        ));
+        assert!(matches!(
+            df.replace_series("x", Int64Array::from_vec(vec![1, 2]).boxed()),
+            Err(Error::NotFound(_))
+        ));
+        assert_eq!(
+            df.get_float64("a").unwrap().values().as_slice(),
+            &[3.0, 4.0]
+        );
+    }
+
+    #[test]
+    fn test_clone_column() {
+        let mut df = df_of(vec![(
+            "a",
+            Float64Array::from(vec![Some(1.0), None]).boxed(),
+        )]);
+        df.clone_column("a", "b").unwrap();
+        assert_eq!(df.names(), vec!["a", "b"]);
+        assert_eq!(df.fields()[1].data_type, DataType::Float64);
+        df.fill_null_float("a", 5.0).unwrap();
+        df.apply("a", |v: Option<f64>| v.map(|n| n * 10.0)).unwrap();
+        assert_eq!(
+            df.get_float64("a").unwrap().values().as_slice(),
+            &[10.0, 50.0]
+        );
+        let b = df.get_float64("b").unwrap();
+        assert_eq!(b.value(0), 1.0);
+        assert!(b.is_null(1));
+        assert!(matches!(df.clone_column("x", "c"), Err(Error::NotFound(_))));
+        assert!(matches!(df.clone_column("a", "b"), Err(Error::DuplicateColumn(n)) if n == "b"));
+    }
+
+    #[test]
+    fn test_ipc_block_compressed() {
+        let mut df = df_of(vec![
+            ("a", Int64Array::from_vec(vec![7; 10_000]).boxed()),
+            (
+                "b",
+                Utf8Array::<i32>::from_slice(vec!["value"; 10_000]).boxed(),
+            ),
+        ]);
+        df.set_metadata_field("source", "test");
+        let plain = df.clone().into_ipc_block().unwrap();
+        for compression in [CompressionType::Lz4, CompressionType::Zstd] {
+            let compressed = df.clone().into_ipc_block_compressed(compression).unwrap();
+            assert!(compressed.len() < plain.len());
+            let parsed = DataFrame::from_ipc_block(&compressed).unwrap();
+            assert!(parsed == df);
+            assert_eq!(parsed.metadata().get("source").unwrap(), "test");
+            let (parsed, metadata) = DataFrame::from_ipc_block_compressed(&compressed).unwrap();
+            assert!(parsed == df);
+            assert_eq!(metadata.get("source").unwrap(), "test");
+        }
+        assert!(DataFrame::from_ipc_block(&plain).unwrap() == df);
+        let (parsed, metadata) = DataFrame::from_ipc_block_compressed(&plain).unwrap();
+        assert!(parsed == df);
+        assert_eq!(&metadata, df.metadata());
+    }
+
+    #[test]
+    fn test_ipc_block_schema() {
+        let mut df = df_of(vec![
+            ("a", Int64Array::from_vec(vec![1, 2]).boxed()),
+            ("b", Float64Array::from_vec(vec![1.0, 2.0]).boxed()),
+        ]);
+        df.set_metadata_field("k", "v");
+        let (schema, metadata) =
+            DataFrame::ipc_block_schema(&df.clone().into_ipc_block().unwrap()).unwrap();
+        assert_eq!(schema.fields, df.fields());
+        assert_eq!(metadata.get("k").unwrap(), "v");
+        // a block with the stream header only, no arrays can be decoded
+        let mut header = Vec::new();
+        {
+            let mut writer = StreamWriter::new(&mut header, WriteOptions::default());
+            writer.start(&df.schema(), None).unwrap();
+        }
+        let (schema, _) = DataFrame::ipc_block_schema(&header).unwrap();
+        assert_eq!(schema.fields, df.fields());
+        assert!(DataFrame::ipc_block_schema(b"garbage").is_err());
+    }
+
+    #[test]
+    fn test_col_metadata() {
+        let mut df = df_of(vec![
+            ("a", Int64Array::from_vec(vec![1, 2]).boxed()),
+            ("b", Float64Array::from_vec(vec![1.0, 2.0]).boxed()),
+        ]);
+        df.set_col_metadata_field("a", "unit", "ms").unwrap();
+        df.set_col_metadata_field_at(1, "unit", "kg").unwrap();
+        df.col_metadata_mut("b")
+            .unwrap()
+            .insert("scale".to_owned(), "2".to_owned());
+        let parsed = DataFrame::from_ipc_block(&df.clone().into_ipc_block().unwrap()).unwrap();
+        assert_eq!(parsed.col_metadata("a").unwrap().get("unit").unwrap(), "ms");
+        assert_eq!(
+            parsed.col_metadata_at(1).unwrap(),
+            df.col_metadata("b").unwrap()
+        );
+        assert_eq!(parsed.col_metadata("b").unwrap().len(), 2);
+        df.clear_col_metadata("a").unwrap();
+        df.clear_col_metadata_at(1).unwrap();
+        assert!(df.col_metadata("a").unwrap().is_empty());
+        assert!(df.col_metadata("b").unwrap().is_empty());
+        assert!(matches!(
+            df.clear_col_metadata("x"),
+            Err(Error::NotFound(_))
+        ));
+        assert!(matches!(
+            df.clear_col_metadata_at(2),
+            Err(Error::OutOfBounds)
+        ));
+        assert!(matches!(
+            df.set_col_metadata_field("x", "k", "v"),
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_bool() {
+        let mut df = df_of(vec![(
+            "a",
+            Utf8Array::<i32>::from(vec![
+                Some("true"),
+                Some("True"),
+                Some("TRUE"),
+                Some("1"),
+                Some("Yes"),
+                Some("ON"),
+                Some("false"),
+                Some("FALSE"),
+                Some("0"),
+                Some("no"),
+                Some("Off"),
+                Some(""),
+                Some("maybe"),
+                Some("2"),
+                None,
+            ])
+            .boxed(),
+        )]);
+        df.parse_bool("a").unwrap();
+        assert_eq!(df.fields()[0].data_type, DataType::Boolean);
+        let values: Vec<Option<bool>> = df.get_bool("a").unwrap().iter().collect();
+        assert_eq!(values[..6], [Some(true); 6]);
+        assert_eq!(values[6..11], [Some(false); 5]);
+        assert_eq!(values[11..], [None; 4]);
+        assert!(matches!(df.parse_bool("a"), Err(Error::TypeMismatch)));
+    }
+
+    #[test]
+    fn test_parse_date() {
+        let dates = |df: &DataFrame| -> Vec<Option<i32>> {
+            let values: &Int32Array = df.data()[0].as_any().downcast_ref().unwrap();
+            values.iter().map(|v| v.copied()).collect()
+        };
+        let mut df = df_of(vec![(
+            "d",
+            Utf8Array::<i32>::from(vec![
+                Some("1970-01-01"),
+                Some("2000-02-29"),
+                Some("1969-12-31"),
+                Some("2001-02-29"),
+                Some("01/02/2000"),
+                None,
+            ])
+            .boxed(),
+        )]);
+        df.parse_date("d", "%Y-%m-%d").unwrap();
+        assert_eq!(df.fields()[0].data_type, DataType::Date32);
+        assert_eq!(df.data()[0].data_type(), &DataType::Date32);
+        assert_eq!(
+            dates(&df),
+            vec![Some(0), Some(11_016), Some(-1), None, None, None]
+        );
+        let mut df = df_of(vec![(
+            "d",
+            Utf8Array::<i64>::from(vec![
+                Some("02/29/2000"),
+                Some("2000-02-29"),
+                Some("13/01/2000"),
+            ])
+            .boxed(),
+        )]);
+        df.parse_date("d", "%m/%d/%Y").unwrap();
+        assert_eq!(dates(&df), vec![Some(11_016), None, None]);
+        assert!(matches!(df.parse_date("d", "%Y"), Err(Error::TypeMismatch)));
+    }
+
+    #[test]
+    fn test_add_index_column() {
+        let mut df = df_of(vec![(
+            "x",
+            Float64Array::from(vec![Some(1.0), None, Some(3.0), Some(4.0)]).boxed(),
+        )]);
+        df.add_index_column("idx", true).unwrap();
+        assert_eq!(df.names(), ["idx", "x"]);
+        let index = df.get_int64("idx").unwrap();
+        assert_eq!(index.null_count(), 0);
+        assert_eq!(index.values().as_slice(), [0, 1, 2, 3]);
+        df.add_index_column("tail", false).unwrap();
+        assert_eq!(df.names(), ["idx", "x", "tail"]);
+        assert!(matches!(
+            df.add_index_column("x", true),
+            Err(Error::DuplicateColumn(n)) if n == "x"
+        ));
+        let sliced = df.try_sliced(1, 2).unwrap();
+        assert_eq!(sliced.get_int64("idx").unwrap().values().as_slice(), [1, 2]);
+        let mut empty = DataFrame::new0();
+        empty.add_index_column("idx", true).unwrap();
+        assert_eq!(empty.rows(), Some(0));
+    }
+
+    #[test]
+    fn test_set_null_at() {
+        let mut df = df_of(vec![
+            (
+                "x",
+                Float64Array::from(vec![Some(1.0), None, Some(3.0)]).boxed(),
+            ),
+            ("i", Int64Array::from_vec(vec![1, 2, 3]).boxed()),
+        ]);
+        df.set_null_at(2, "x").unwrap();
+        let values = df.get_float64("x").unwrap();
+        assert_eq!(values.get(2), None);
+        assert_eq!(values.null_count(), 2);
+        assert_eq!(values.values().as_slice()[2], 3.0);
+        df.set_null_at(0, "i").unwrap();
+        let values = df.get_int64("i").unwrap();
+        assert_eq!(values.null_count(), 1);
+        assert_eq!(
+            values.iter().collect::<Vec<_>>(),
+            [None, Some(&2), Some(&3)]
+        );
+        assert!(matches!(df.set_null_at(3, "x"), Err(Error::OutOfBounds)));
+        assert!(matches!(df.set_null_at(0, "y"), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn test_float_hashmap() {
+        let data: HashMap<String, Vec<Option<f64>>> = [
+            ("c", vec![Some(1.0), None, Some(3.0), Some(4.0), None]),
+            ("a", vec![None, None, None, None, None]),
+            ("b", vec![Some(0.5), Some(1.5), Some(2.5), None, Some(-1.0)]),
+        ]
+        .into_iter()
+        .map(|(name, values)| (name.to_owned(), values))
+        .collect();
+        let df = DataFrame::from_float_hashmap(data.clone()).unwrap();
+        assert_eq!(df.names(), ["a", "b", "c"]);
+        assert_eq!(df.rows(), Some(5));
+        assert_eq!(df.get_float64("c").unwrap().null_count(), 2);
+        assert_eq!(df.to_float_hashmap().unwrap(), data);
+        let mut data = data;
+        data.insert("d".to_owned(), vec![Some(1.0)]);
+        assert!(matches!(
+            DataFrame::from_float_hashmap(data),
+            Err(Error::RowsNotMatch)
+        ));
+        let mut df = df;
+        df.add_series0("i", Int64Array::from_vec(vec![1; 5]).boxed())
+            .unwrap();
+        assert!(matches!(df.to_float_hashmap(), Err(Error::TypeMismatch)));
+    }
+
+    #[test]
+    fn test_display() {
+        let mut strings = vec![
+            Some("short"),
+            None,
+            Some("a string longer than twenty chars"),
+        ];
+        strings.resize(12, Some("x"));
+        let mut floats = vec![1.5; 12];
+        floats[0] = 0.000_012_345_678_901_234_568;
+        let df = df_of(vec![
+            ("id", Int64Array::from_vec((0..12).collect()).boxed()),
+            ("s", Utf8Array::<i32>::from(strings).boxed()),
+            ("d", Float64Array::from_vec(floats).boxed()),
+            (
+                "a_column_name_that_is_far_too_long",
+                BooleanArray::from_slice([true; 12]).boxed(),
+            ),
+        ]);
+        let mut expected = vec![
+            "id    | s                    | d                       | a_column_name_that_is_far_too…",
+            "Int64 | Utf8                 | Float64                 | Boolean",
+            "------+----------------------+-------------------------+-------------------------------",
+            "0     | short                | 0.000012345678901234568 | true",
+            "1     | NULL                 | 1.5                     | true",
+            "2     | a string longer tha… | 1.5                     | true",
+        ];
+        let rows: Vec<String> = (3..10)
+            .map(|i| {
+                format!(
+                    "{}     | x                    | 1.5                     | true",
+                    i
+                )
+            })
+            .collect();
+        expected.extend(rows.iter().map(String::as_str));
+        expected.push("... 2 more rows");
+        let output = df.to_string();
+        assert_eq!(
+            output.lines().map(str::trim_end).collect::<Vec<_>>(),
+            expected
+        );
+        let output = df.head(2).to_string();
+        assert_eq!(output.lines().count(), 5);
+        assert!(!output.contains("more rows"));
+        assert_eq!(DataFrame::new0().to_string(), "(empty)\n");
+    }
+
+    #[test]
+    fn test_rows_iter() {
+        let df = df_of(vec![
+            (
+                "f",
+                Float64Array::from(vec![Some(1.5), None, Some(3.0)]).boxed(),
+            ),
+            ("i", Int64Array::from(vec![None, Some(2), Some(3)]).boxed()),
+            (
+                "b",
+                BooleanArray::from(vec![Some(true), Some(false), None]).boxed(),
+            ),
+            (
+                "s",
+                Utf8Array::<i32>::from(vec![Some("a"), None, Some("c")]).boxed(),
+            ),
+            ("l", Utf8Array::<i64>::from_slice(["x", "y", "z"]).boxed()),
+        ]);
+        let mut iter = df.rows_iter();
+        assert_eq!(iter.len(), 3);
+        let row = iter.next().unwrap();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(row.index(), 0);
+        assert_eq!(row.get_float64("f"), Some(1.5));
+        assert_eq!(row.get_int64("i"), None);
+        assert_eq!(row.get_bool("b"), Some(true));
+        assert_eq!(row.get_utf8("s"), Some("a"));
+        assert_eq!(row.get_utf8("l"), Some("x"));
+        assert_eq!(row.get_float64("i"), None);
+        assert_eq!(row.get_utf8("f"), None);
+        assert_eq!(row.get_int64("missing"), None);
+        let rows: Vec<Row> = iter.collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].get_float64("f"), None);
+        assert_eq!(rows[0].get_utf8("s"), None);
+        assert_eq!(rows[1].get_bool("b"), None);
+        assert_eq!(rows[1].get_int64("i"), Some(3));
+        assert_eq!(df.try_sliced(0, 0).unwrap().rows_iter().count(), 0);
+        assert_eq!(DataFrame::new0().rows_iter().len(), 0);
+        // a field with no data
+        let df = DataFrame::from_parts(
+            vec![
+                Field::new("f", DataType::Float64, true),
+                Field::new("g", DataType::Float64, true),
+            ],
+            vec![Float64Array::from_vec(vec![1.0]).boxed()],
+            None,
+        )
+        .unwrap();
+        let row = df.rows_iter().next().unwrap();
+        assert_eq!(row.get_float64("f"), Some(1.0));
+        assert_eq!(row.get_float64("g"), None);
+        assert_eq!(row.get_utf8("g"), None);
+    }
+
+    #[test]
+    fn test_ipc_file() {
+        let mut df = df_of(vec![
+            ("a", Int64Array::from(vec![Some(1), None, Some(3)]).boxed()),
+            ("b", Float64Array::from_vec(vec![0.5, 1.5, 2.5]).boxed()),
+            (
+                "c",
+                Utf8Array::<i32>::from(vec![Some("x"), Some("y"), None]).boxed(),
+            ),
+        ]);
+        df.set_metadata_field("source", "test");
+        let path = std::env::temp_dir().join(format!("arrow_util_{}.arrow", std::process::id()));
+        df.clone().write_ipc_file(&path).unwrap();
+        let (parsed, metadata) = DataFrame::read_ipc_file(&path).unwrap();
+        let (projected, _) = DataFrame::read_ipc_file_projected(&path, &["c", "a"]).unwrap();
+        let (repeated, _) = DataFrame::read_ipc_file_projected(&path, &["c", "c", "a"]).unwrap();
+        let missing = DataFrame::read_ipc_file_projected(&path, &["a", "x"]);
+        std::fs::remove_file(&path).unwrap();
+        assert!(parsed == df);
+        assert_eq!(metadata.get("source").unwrap(), "test");
+        assert_eq!(parsed.metadata(), df.metadata());
+        let expected = df.select(&["c", "a"]).unwrap();
+        assert_eq!(projected.names(), ["c", "a"]);
+        assert!(projected == expected);
+        assert_eq!(projected.metadata().get("source").unwrap(), "test");
+        assert!(repeated == expected);
+        assert!(matches!(
+            missing,
+            Err(ArrowError::InvalidArgumentError(e)) if e.contains('x')
+        ));
+    }
+
+    #[test]
+    fn test_ipc_block_chunks() {
+        let mut df = df_of(vec![
+            (
+                "a",
+                Int64Array::from(vec![Some(1), None, Some(3), Some(4), Some(5)]).boxed(),
+            ),
+            (
+                "b",
+                Utf8Array::<i32>::from(vec![Some("v"), Some("w"), None, Some("y"), Some("z")])
+                    .boxed(),
+            ),
+        ]);
+        df.set_metadata_field("source", "test");
+        let parts = [
+            df.try_sliced(0, 2).unwrap(),
+            df.try_sliced(2, 1).unwrap(),
+            df.try_sliced(3, 2).unwrap(),
+        ];
+        let stream = |chunks: &[DataFrame]| {
+            let mut block = Vec::new();
+            {
+                let mut writer = StreamWriter::new(&mut block, WriteOptions::default());
+                writer.start(&df.schema(), None).unwrap();
+                for chunk in chunks {
+                    writer
+                        .write(&Chunk::new(chunk.data().to_vec()), None)
+                        .unwrap();
+                }
+                writer.finish().unwrap();
+            }
+            block
+        };
+        let block = stream(&parts);
+        let (chunks, metadata) = DataFrame::from_ipc_block_all_chunks(&block).unwrap();
+        assert_eq!(chunks.len(), 3);
+        for (chunk, part) in chunks.iter().zip(&parts) {
+            assert!(chunk == part);
+            assert_eq!(chunk.metadata(), df.metadata());
+        }
+        assert_eq!(&metadata, df.metadata());
+        let (concat, metadata) = DataFrame::from_ipc_block_concat(&block).unwrap();
+        assert!(concat == df);
+        assert_eq!(concat.metadata(), df.metadata());
+        assert_eq!(&metadata, df.metadata());
+        assert!(DataFrame::from_ipc_block(&block).unwrap() == parts[0]);
+        let block = stream(&[]);
+        let (chunks, metadata) = DataFrame::from_ipc_block_all_chunks(&block).unwrap();
+        assert!(chunks.is_empty());
+        assert_eq!(&metadata, df.metadata());
+        let (concat, metadata) = DataFrame::from_ipc_block_concat(&block).unwrap();
+        assert!(concat.is_empty());
+        assert_eq!(concat.metadata(), df.metadata());
+        assert_eq!(&metadata, df.metadata());
+    }
+
+    #[test]
+    #[ignore = "timing test, run with --ignored --release"]
+    fn test_ipc_block_schema_timing() {
+        let rows = 1_000_000;
+        let df = df_of(vec![
+            ("a", Int64Array::from_vec((0..rows).collect()).boxed()),
+            (
+                "b",
+                Utf8Array::<i32>::from_iter_values((0..rows).map(|i| i.to_string())).boxed(),
+            ),
+        ]);
+        let block = df.into_ipc_block().unwrap();
+        let iterations = 10;
+        let start = std::time::Instant::now();
+        for _ in 0..iterations {
+            DataFrame::from_ipc_block(&block).unwrap();
+        }
+        let full = start.elapsed();
+        let start = std::time::Instant::now();
+        for _ in 0..iterations {
+            DataFrame::ipc_block_schema(&block).unwrap();
+        }
+        let schema_only = start.elapsed();
+        println!(
+            "from_ipc_block: {:?}, ipc_block_schema: {:?}",
+            full, schema_only
+        );
+        assert!(schema_only * 10 < full);
+    }
+}
@@ -1,5 +1,5 @@
 #![ doc = include_str!( concat!( env!( "CARGO_MANIFEST_DIR" ), "/", "README.md" ) ) ]
-use chrono::Local;
+use chrono::{DateTime, Local, NaiveDateTime, SecondsFormat, Utc};
 use std::fmt;
 
 #[cfg(feature = "arrow2_ih")]
@@ -8,10 +8,15 @@ extern crate arrow2_ih as arrow2;
 pub mod convert;
 mod df;
 
-pub use df::{Chunk, DataFrame, DataType, Metadata, Schema, Series, TimeUnit};
+pub use df::{
+    Chunk, CompressionType, DataFrame, DataType, Metadata, Row, RowIter, Schema, Series,
+    SortByOptions, TimeUnit,
+};
 
 mod ops;
-pub use ops::concat::concat;
+pub use ops::concat::{concat, concat_dataframes};
+pub use ops::group::AggFunc;
+pub use ops::rank::RankMethod;
 
 pub mod db;
 
@@ -21,11 +26,17 @@ pub enum Error {
     RowsNotMatch,
     ColsNotMatch,
     TypeMismatch,
+    SchemaMismatch,
+    Overflow,
     AlreadyExists(String),
+    DuplicateColumn(String),
     Arrow(arrow2::error::Error),
     NotFound(String),
+    InvalidArgument(String),
     Unimplemented(String),
     Other(String),
+    ParseError(String),
+    Io(std::io::Error),
     #[cfg(feature = "sqlx")]
     Database(sqlx::Error),
     #[cfg(feature = "serde_json")]
@@ -46,6 +57,13 @@ impl Error {
     }
 }
 
+impl From<std::io::Error> for Error {
+    #[inline]
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
 impl From<fmt::Error> for Error {
     #[inline]
     fn from(err: fmt::Error) -> Self {
@@ -74,11 +92,17 @@ impl fmt::Display for Error {
             Error::RowsNotMatch => write!(f, "row count does not match"),
             Error::ColsNotMatch => write!(f, "column count does not match"),
             Error::TypeMismatch => write!(f, "type does not match"),
+            Error::SchemaMismatch => write!(f, "schema does not match"),
+            Error::Overflow => write!(f, "numeric overflow"),
             Error::AlreadyExists(s) => write!(f, "already exists: {}", s),
+            Error::DuplicateColumn(s) => write!(f, "duplicate column: {}", s),
             Error::Arrow(e) => write!(f, "{}", e),
             Error::NotFound(s) => write!(f, "not found: {}", s),
+            Error::InvalidArgument(s) => write!(f, "invalid argument: {}", s),
             Error::Unimplemented(s) => write!(f, "feature/type not implemented: {}", s),
             Error::Other(e) => write!(f, "{}", e),
+            Error::ParseError(s) => write!(f, "parse error: {}", s),
+            Error::Io(e) => write!(f, "I/O error: {}", e),
             #[cfg(feature = "sqlx")]
             Error::Database(e) => write!(f, "database error: {}", e),
             #[cfg(feature = "serde_json")]
@@ -135,3 +159,34 @@ impl Time {
         self.sec * 1_000 + self.nsec / 1_000_000
     }
 }
+
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+fn naive_from_timestamp(ts: i64, time_unit: &TimeUnit) -> Option<NaiveDateTime> {
+    match time_unit {
+        TimeUnit::Second => NaiveDateTime::from_timestamp_opt(ts, 0),
+        TimeUnit::Millisecond => NaiveDateTime::from_timestamp_millis(ts),
+        TimeUnit::Microsecond => NaiveDateTime::from_timestamp_micros(ts),
+        TimeUnit::Nanosecond => NaiveDateTime::from_timestamp_opt(
+            ts.div_euclid(1_000_000_000),
+            ts.rem_euclid(1_000_000_000) as u32,
+        ),
+    }
+}
+
+fn naive_to_timestamp(dt: &NaiveDateTime, time_unit: &TimeUnit) -> Option<i64> {
+    let sec = dt.timestamp();
+    let nsec = i64::from(dt.timestamp_subsec_nanos());
+    match time_unit {
+        TimeUnit::Second => Some(sec),
+        TimeUnit::Millisecond => sec.checked_mul(1_000)?.checked_add(nsec / 1_000_000),
+        TimeUnit::Microsecond => sec.checked_mul(1_000_000)?.checked_add(nsec / 1_000),
+        TimeUnit::Nanosecond => sec.checked_mul(1_000_000_000)?.checked_add(nsec),
+    }
+}
+
+fn timestamp_rfc3339(ts: i64, time_unit: &TimeUnit) -> Result<String, Error> {
+    let dt = naive_from_timestamp(ts, time_unit)
+        .ok_or_else(|| Error::other(format!("invalid timestamp: {}", ts)))?;
+    Ok(DateTime::<Utc>::from_utc(dt, Utc).to_rfc3339_opts(SecondsFormat::AutoSi, true))
+}
@@ -86,3 +86,87 @@ pub fn concat(data_frames: &[&DataFrame]) -> Result<DataFrame, Error> {
         DataFrame::from_parts(fields, data, Some(meta))
     }
 }
+
+/// vertically concatenate data frames with the same schema (column names and types, in the same
+/// order)
+///
+/// Data frames with no columns are skipped, the same way as in [`DataFrame::append`]. The
+/// metadata of the first data frame is used
+pub fn concat_dataframes(frames: &[&DataFrame]) -> Result<DataFrame, Error> {
+    let mut non_empty = frames.iter().filter(|df| !df.is_empty());
+    if let Some(first) = non_empty.next() {
+        for df in non_empty {
+            first.check_same_schema(df)?;
+        }
+        let mut data: Vec<Series> = Vec::with_capacity(first.fields().len());
+        for i in 0..first.fields().len() {
+            let series: Vec<&dyn Array> = frames
+                .iter()
+                .filter(|df| !df.is_empty())
+                .map(|df| df.data()[i].as_ref())
+                .collect();
+            data.push(compute::concatenate::concatenate(&series)?);
+        }
+        DataFrame::from_parts(
+            first.fields().to_vec(),
+            data,
+            Some(frames[0].metadata().clone()),
+        )
+    } else {
+        Ok(frames.first().copied().cloned().unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow2::array::{Float64Array, Utf8Array};
+
+    fn concat_df(values: Vec<Option<f64>>, names: Vec<Option<&str>>) -> DataFrame {
+        let mut df = DataFrame::new0();
+        df.add_series0("x", Float64Array::from(values).boxed())
+            .unwrap();
+        df.add_series0("s", Utf8Array::<i32>::from(names).boxed())
+            .unwrap();
+        df
+    }
+
+    #[test]
+    fn test_concat_dataframes() {
+        let a = concat_df(vec![Some(1.0), None], vec![None, Some("b")]);
+        let b = concat_df(vec![Some(3.0)], vec![Some("c")]);
+        let c = concat_df(
+            vec![None, Some(5.0), Some(6.0)],
+            vec![Some("d"), None, Some("f")],
+        );
+        let df = concat_dataframes(&[&a, &DataFrame::new0(), &b, &c]).unwrap();
+        assert_eq!(
+            df.rows(),
+            Some(a.rows().unwrap() + b.rows().unwrap() + c.rows().unwrap())
+        );
+        assert_eq!(df.names(), ["x", "s"]);
+        assert_eq!(
+            df.get_float64("x").unwrap(),
+            &Float64Array::from(vec![Some(1.0), None, Some(3.0), None, Some(5.0), Some(6.0)])
+        );
+        assert_eq!(
+            df.get_utf8_iter("s").unwrap().collect::<Vec<_>>(),
+            [None, Some("b"), Some("c"), Some("d"), None, Some("f")]
+        );
+        assert!(concat_dataframes(&[]).unwrap().is_empty());
+        let mut other = DataFrame::new0();
+        other
+            .add_series0("x", Float64Array::from_vec(vec![1.0]).boxed())
+            .unwrap();
+        assert!(matches!(
+            concat_dataframes(&[&a, &other]),
+            Err(Error::SchemaMismatch)
+        ));
+        let mut other = concat_df(vec![Some(1.0)], vec![Some("a")]);
+        other.rename("s", "t").unwrap();
+        assert!(matches!(
+            concat_dataframes(&[&a, &other]),
+            Err(Error::SchemaMismatch)
+        ));
+    }
+}
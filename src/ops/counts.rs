@@ -0,0 +1,275 @@
+#[cfg(feature = "arrow2_ih")]
+extern crate arrow2_ih as arrow2;
+
+use crate::df::{DataFrame, Series};
+use crate::ops::concat::concat_dataframes;
+use crate::Error;
+use arrow2::array::{Array, BooleanArray, Int64Array, PrimitiveArray, Utf8Array};
+use arrow2::compute;
+use arrow2::datatypes::DataType;
+use std::collections::{HashMap, HashSet};
+
+/// Hashable value of a single cell
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub(crate) enum Key<'a> {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(u64),
+    Str(&'a str),
+}
+
+impl Key<'_> {
+    #[inline]
+    fn float(value: f64) -> Self {
+        if value.is_nan() {
+            Key::Float(f64::NAN.to_bits())
+        } else if value == 0.0 {
+            Key::Float(0f64.to_bits())
+        } else {
+            Key::Float(value.to_bits())
+        }
+    }
+}
+
+/// Hashable keys for all values of a series
+pub(crate) fn keys(serie: &dyn Array) -> Result<Vec<Key<'_>>, Error> {
+    macro_rules! collect {
+        ($kind: ty, $key: expr) => {
+            if let Some(arr) = serie.as_any().downcast_ref::<$kind>() {
+                return Ok(arr.iter().map(|v| v.map_or(Key::Null, $key)).collect());
+            }
+        };
+    }
+    collect!(PrimitiveArray<i64>, |v| Key::Int(*v));
+    collect!(PrimitiveArray<i32>, |v| Key::Int(i64::from(*v)));
+    collect!(PrimitiveArray<i16>, |v| Key::Int(i64::from(*v)));
+    collect!(PrimitiveArray<i8>, |v| Key::Int(i64::from(*v)));
+    collect!(PrimitiveArray<u64>, |v| Key::UInt(*v));
+    collect!(PrimitiveArray<u32>, |v| Key::UInt(u64::from(*v)));
+    collect!(PrimitiveArray<u16>, |v| Key::UInt(u64::from(*v)));
+    collect!(PrimitiveArray<u8>, |v| Key::UInt(u64::from(*v)));
+    collect!(PrimitiveArray<f64>, |v| Key::float(*v));
+    collect!(PrimitiveArray<f32>, |v| Key::float(f64::from(*v)));
+    collect!(BooleanArray, Key::Bool);
+    collect!(Utf8Array<i32>, Key::Str);
+    collect!(Utf8Array<i64>, Key::Str);
+    Err(Error::Unimplemented(format!("{:?}", serie.data_type())))
+}
+
+/// Distinct keys with the index of their first occurrence and the number of occurrences, in
+/// order of the first occurrence
+pub(crate) fn distinct<'a>(keys: &[Key<'a>]) -> Vec<(Key<'a>, usize, usize)> {
+    let mut positions: HashMap<Key, usize> = HashMap::new();
+    let mut result: Vec<(Key, usize, usize)> = Vec::new();
+    for (i, key) in keys.iter().enumerate() {
+        if let Some(pos) = positions.get(key) {
+            result[*pos].2 += 1;
+        } else {
+            positions.insert(*key, result.len());
+            result.push((*key, i, 1));
+        }
+    }
+    result
+}
+
+/// Row indices of each distinct key, in order of the first occurrence
+pub(crate) fn groups(keys: &[Key]) -> Vec<Vec<usize>> {
+    let mut positions: HashMap<Key, usize> = HashMap::new();
+    let mut result: Vec<Vec<usize>> = Vec::new();
+    for (i, key) in keys.iter().enumerate() {
+        if let Some(pos) = positions.get(key) {
+            result[*pos].push(i);
+        } else {
+            positions.insert(*key, result.len());
+            result.push(vec![i]);
+        }
+    }
+    result
+}
+
+impl DataFrame {
+    /// Count occurrences of each unique value in a column
+    ///
+    /// Returns a data frame with "value" (the same type as the source column) and "count"
+    /// (Int64) columns, sorted by count descending. Nulls are counted as a separate value
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn value_counts(&self, name: &str) -> Result<Self, Error> {
+        let (serie, data_type) = self
+            .get_series(name)
+            .ok_or_else(|| Error::NotFound(name.to_owned()))?;
+        let mut counts = distinct(&keys(serie.as_ref())?);
+        counts.sort_by(|a, b| b.2.cmp(&a.2));
+        let indices = Int64Array::from_iter_values(counts.iter().map(|v| v.1 as i64));
+        let values = compute::take::take(serie.as_ref(), &indices)?;
+        let counts = Int64Array::from_iter_values(counts.iter().map(|v| v.2 as i64));
+        let mut df = DataFrame::new(Some(2));
+        df.add_series("value", values, Some(data_type.clone()), None)?;
+        df.add_series("count", counts.boxed(), Some(DataType::Int64), None)?;
+        Ok(df)
+    }
+    /// Distinct values of a column, in order of the first occurrence
+    ///
+    /// Null is included at most once
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn unique_values(&self, name: &str) -> Result<Series, Error> {
+        let (serie, _) = self
+            .get_series(name)
+            .ok_or_else(|| Error::NotFound(name.to_owned()))?;
+        let indices = Int64Array::from_iter_values(
+            distinct(&keys(serie.as_ref())?)
+                .into_iter()
+                .map(|v| v.1 as i64),
+        );
+        Ok(compute::take::take(serie.as_ref(), &indices)?)
+    }
+    /// Number of distinct values in a column, null is counted as a value
+    pub fn n_unique(&self, name: &str) -> Result<usize, Error> {
+        let (serie, _) = self
+            .get_series(name)
+            .ok_or_else(|| Error::NotFound(name.to_owned()))?;
+        Ok(keys(serie.as_ref())?
+            .into_iter()
+            .collect::<HashSet<Key>>()
+            .len())
+    }
+    /// Vertically concatenate two data frames with the same schema and remove duplicate rows,
+    /// the first occurrences are kept
+    ///
+    /// Rows are equal if all their values are equal, nulls are equal to each other
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn union_distinct(&self, other: &DataFrame) -> Result<Self, Error> {
+        let df = concat_dataframes(&[self, other])?;
+        let columns = df
+            .data()
+            .iter()
+            .map(|serie| keys(serie.as_ref()))
+            .collect::<Result<Vec<Vec<Key>>, Error>>()?;
+        let mut seen: HashSet<Vec<Key>> = HashSet::new();
+        let mut indices: Vec<i64> = Vec::new();
+        for i in 0..df.rows().unwrap_or_default() {
+            if seen.insert(columns.iter().map(|c| c[i]).collect()) {
+                indices.push(i as i64);
+            }
+        }
+        let indices = Int64Array::from_vec(indices);
+        let data = df
+            .data()
+            .iter()
+            .map(|serie| compute::take::take(serie.as_ref(), &indices))
+            .collect::<Result<Vec<Series>, _>>()?;
+        DataFrame::from_parts(df.fields().to_vec(), data, Some(df.metadata().clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn df_of(name: &str, serie: Series) -> DataFrame {
+        let mut df = DataFrame::new0();
+        df.add_series0(name, serie).unwrap();
+        df
+    }
+
+    #[test]
+    fn test_value_counts() {
+        let df = df_of("a", Utf8Array::<i32>::from_slice(["x", "y", "z"]).boxed());
+        let counts = df.value_counts("a").unwrap();
+        assert_eq!(counts.names(), vec!["value", "count"]);
+        assert_eq!(
+            counts.get_int64("count").unwrap().values().as_slice(),
+            &[1, 1, 1]
+        );
+        assert_eq!(
+            counts.get_utf8_iter("value").unwrap().collect::<Vec<_>>(),
+            vec![Some("x"), Some("y"), Some("z")]
+        );
+        let df = df_of("a", Int64Array::from_vec(vec![7; 4]).boxed());
+        let counts = df.value_counts("a").unwrap();
+        assert_eq!(counts.get_int64("value").unwrap().values().as_slice(), &[7]);
+        assert_eq!(counts.get_int64("count").unwrap().values().as_slice(), &[4]);
+        let df = df_of(
+            "a",
+            Int64Array::from(vec![Some(1), None, Some(2), None, Some(2), None]).boxed(),
+        );
+        let counts = df.value_counts("a").unwrap();
+        assert_eq!(
+            counts
+                .get_int64("value")
+                .unwrap()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![None, Some(2), Some(1)]
+        );
+        assert_eq!(
+            counts.get_int64("count").unwrap().values().as_slice(),
+            &[3, 2, 1]
+        );
+        assert!(matches!(df.value_counts("x"), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn test_unique_values() {
+        let df = df_of(
+            "a",
+            PrimitiveArray::<f64>::from(vec![
+                Some(2.0),
+                None,
+                Some(1.0),
+                Some(2.0),
+                None,
+                Some(-0.0),
+                Some(0.0),
+            ])
+            .boxed(),
+        );
+        let unique = df.unique_values("a").unwrap();
+        let values: &PrimitiveArray<f64> = unique.as_any().downcast_ref().unwrap();
+        assert_eq!(
+            values.iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(2.0), None, Some(1.0), Some(-0.0)]
+        );
+        assert_eq!(df.n_unique("a").unwrap(), 4);
+        let df = df_of("a", unique);
+        let again = df.unique_values("a").unwrap();
+        assert!(arrow2::array::equal(again.as_ref(), df.data()[0].as_ref()));
+        assert_eq!(df.n_unique("a").unwrap(), 4);
+        let df = df_of("a", Utf8Array::<i64>::from_slice(["b", "a", "b"]).boxed());
+        assert_eq!(df.n_unique("a").unwrap(), 2);
+        assert_eq!(df.unique_values("a").unwrap().len(), 2);
+        assert!(matches!(df.n_unique("x"), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn test_union_distinct() {
+        let frame = |x: Vec<Option<i64>>, s: Vec<&str>| {
+            let mut df = df_of("x", Int64Array::from(x).boxed());
+            df.add_series0("s", Utf8Array::<i32>::from_slice(s).boxed())
+                .unwrap();
+            df
+        };
+        let a = frame(
+            vec![Some(1), Some(1), None, Some(2)],
+            vec!["a", "a", "n", "b"],
+        );
+        let b = frame(vec![Some(2), None, Some(3), None], vec!["b", "n", "c", "a"]);
+        let df = a.union_distinct(&b).unwrap();
+        assert_eq!(df.names(), ["x", "s"]);
+        assert_eq!(
+            df.get_int64("x").unwrap(),
+            &Int64Array::from(vec![Some(1), None, Some(2), Some(3), None])
+        );
+        assert_eq!(
+            df.get_utf8_iter("s").unwrap().collect::<Vec<_>>(),
+            [Some("a"), Some("n"), Some("b"), Some("c"), Some("a")]
+        );
+        let other = df_of("x", Int64Array::from_vec(vec![1]).boxed());
+        assert!(matches!(
+            a.union_distinct(&other),
+            Err(Error::SchemaMismatch)
+        ));
+    }
+}
@@ -0,0 +1,354 @@
+#[cfg(feature = "arrow2_ih")]
+extern crate arrow2_ih as arrow2;
+
+use crate::df::{DataFrame, Series};
+use crate::ops::stats::percentile_sorted;
+use crate::Error;
+use arrow2::array::{Float64Array, Int64Array, Int8Array, Utf8Array};
+use arrow2::datatypes::DataType;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap};
+
+fn cut_values(
+    values: &Float64Array,
+    bins: &[f64],
+    labels: &[&str],
+    include_last: bool,
+) -> Result<Series, Error> {
+    if bins.len() < 2 {
+        return Err(Error::InvalidArgument(
+            "at least two bin edges required".to_owned(),
+        ));
+    }
+    if labels.len() != bins.len() - 1 {
+        return Err(Error::InvalidArgument(format!(
+            "{} labels required, got {}",
+            bins.len() - 1,
+            labels.len()
+        )));
+    }
+    if bins
+        .windows(2)
+        .any(|w| w[0].partial_cmp(&w[1]) != Some(Ordering::Less))
+    {
+        return Err(Error::InvalidArgument(
+            "bin edges must be strictly increasing".to_owned(),
+        ));
+    }
+    let last = bins[bins.len() - 1];
+    let result: Utf8Array<i32> = values
+        .iter()
+        .map(|v| {
+            v.and_then(|x| {
+                if include_last && *x == last {
+                    labels.last().copied()
+                } else {
+                    bins.windows(2)
+                        .position(|w| *x >= w[0] && *x < w[1])
+                        .map(|pos| labels[pos])
+                }
+            })
+        })
+        .collect();
+    Ok(result.boxed())
+}
+
+impl DataFrame {
+    /// Assign Float64 column values to labeled bins
+    ///
+    /// The bin edges define right-open intervals [b0, b1), [b1, b2) ... Values out of the bins
+    /// (including the last edge), NaNs and nulls produce nulls
+    #[inline]
+    pub fn cut(&self, name: &str, bins: &[f64], labels: &[&str]) -> Result<Series, Error> {
+        cut_values(self.get_float64(name)?, bins, labels, false)
+    }
+    /// Assign Float64 column values to labeled bins, the bin edges are calculated as the column
+    /// quantiles (0.0..=1.0)
+    ///
+    /// See [`DataFrame::cut`], unlike it, the last interval includes its right edge, so the
+    /// maximum is binned when the last quantile is 1.0. NaNs are ignored. Returns [`Error::InvalidArgument`] if
+    /// calculated edges are not unique
+    pub fn qcut(&self, name: &str, quantiles: &[f64], labels: &[&str]) -> Result<Series, Error> {
+        if let Some(q) = quantiles.iter().find(|q| !(0.0..=1.0).contains(*q)) {
+            return Err(Error::InvalidArgument(format!(
+                "quantile must be in [0.0, 1.0], got {}",
+                q
+            )));
+        }
+        let values = self.get_float64(name)?;
+        let mut sorted: Vec<f64> = values
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|v| !v.is_nan())
+            .collect();
+        if sorted.is_empty() {
+            return Ok(Utf8Array::<i32>::new_null(DataType::Utf8, values.len()).boxed());
+        }
+        sorted.sort_by(f64::total_cmp);
+        let bins: Vec<f64> = quantiles
+            .iter()
+            .map(|q| percentile_sorted(&sorted, *q))
+            .collect();
+        cut_values(values, &bins, labels, true)
+    }
+    /// Replace a Utf8/LargeUtf8 column with Int8 columns named {name}_{value}, one per each
+    /// distinct value (sorted), containing 1 where the row matches the value and 0 otherwise
+    ///
+    /// Null rows produce 0 in all the columns
+    pub fn one_hot_encode(&mut self, name: &str) -> Result<(), Error> {
+        let index = self.column_index(name)?;
+        let values: Vec<Option<String>> = self
+            .get_utf8_iter(name)?
+            .map(|v| v.map(ToOwned::to_owned))
+            .collect();
+        let distinct: BTreeSet<&str> = values.iter().flatten().map(String::as_str).collect();
+        let names: Vec<String> = distinct.iter().map(|v| format!("{}_{}", name, v)).collect();
+        for n in &names {
+            if self.get_column_index(n).is_some() {
+                return Err(Error::AlreadyExists(n.clone()));
+            }
+        }
+        let columns: Vec<Series> = distinct
+            .iter()
+            .map(|d| {
+                Int8Array::from_iter_values(
+                    values.iter().map(|v| i8::from(v.as_deref() == Some(*d))),
+                )
+                .boxed()
+            })
+            .collect();
+        self.drop_column(name)?;
+        for (i, (n, column)) in names.iter().zip(columns).enumerate() {
+            self.insert_series(n, column, index + i, Some(DataType::Int8), None)?;
+        }
+        Ok(())
+    }
+    /// Replace a Utf8/LargeUtf8 column with Int64 codes of its values, assigned in
+    /// lexicographic order. Nulls remain null
+    ///
+    /// Returns the mapping of values to codes
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn label_encode(&mut self, name: &str) -> Result<HashMap<String, i64>, Error> {
+        let distinct: BTreeSet<&str> = self.get_utf8_iter(name)?.flatten().collect();
+        let mapping: HashMap<String, i64> = distinct
+            .into_iter()
+            .enumerate()
+            .map(|(code, v)| (v.to_owned(), code as i64))
+            .collect();
+        let codes: Int64Array = self
+            .get_utf8_iter(name)?
+            .map(|v| v.and_then(|s| mapping.get(s).copied()))
+            .collect();
+        self.set_data_type(name, DataType::Int64)?;
+        self.replace_series(name, codes.boxed())?;
+        Ok(mapping)
+    }
+    /// Replace an Int64 column of codes with Utf8 values, using the mapping returned by
+    /// [`DataFrame::label_encode`]
+    ///
+    /// Nulls and unknown codes produce nulls
+    pub fn label_decode(
+        &mut self,
+        name: &str,
+        mapping: &HashMap<String, i64>,
+    ) -> Result<(), Error> {
+        let labels: HashMap<i64, &str> = mapping.iter().map(|(k, v)| (*v, k.as_str())).collect();
+        let values: Utf8Array<i32> = self
+            .get_int64(name)?
+            .iter()
+            .map(|v| v.and_then(|code| labels.get(code).copied()))
+            .collect();
+        self.set_data_type(name, DataType::Utf8)?;
+        self.replace_series(name, values.boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(serie: &Series) -> Vec<Option<&str>> {
+        serie
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()
+            .unwrap()
+            .iter()
+            .collect()
+    }
+
+    fn float_df(values: Vec<Option<f64>>) -> DataFrame {
+        let mut df = DataFrame::new0();
+        df.add_series0("x", Float64Array::from(values).boxed())
+            .unwrap();
+        df
+    }
+
+    #[test]
+    fn test_one_hot_encode() {
+        let mut df = DataFrame::new0();
+        df.add_series0("id", Int64Array::from_vec(vec![1, 2, 3, 4, 5]).boxed())
+            .unwrap();
+        df.add_series0(
+            "color",
+            Utf8Array::<i32>::from(vec![
+                Some("red"),
+                Some("blue"),
+                None,
+                Some("red"),
+                Some("green"),
+            ])
+            .boxed(),
+        )
+        .unwrap();
+        df.one_hot_encode("color").unwrap();
+        assert_eq!(df.names(), ["id", "color_blue", "color_green", "color_red"]);
+        assert!(df.fields()[1..]
+            .iter()
+            .all(|f| f.data_type() == &DataType::Int8));
+        let columns: Vec<&Int8Array> = df.data()[1..]
+            .iter()
+            .map(|s| s.as_any().downcast_ref().unwrap())
+            .collect();
+        let sums: Vec<i8> = (0..5)
+            .map(|row| columns.iter().map(|c| c.value(row)).sum())
+            .collect();
+        assert_eq!(sums, [1, 1, 0, 1, 1]);
+        assert_eq!(columns[2].values().as_slice(), [1, 0, 0, 1, 0]);
+        assert!(matches!(
+            df.one_hot_encode("color"),
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_label_encode_decode() {
+        let mut df = DataFrame::new0();
+        df.add_series0(
+            "s",
+            Utf8Array::<i32>::from(vec![Some("b"), None, Some("a"), Some("b")]).boxed(),
+        )
+        .unwrap();
+        df.add_series0("x", Int64Array::from_vec(vec![1, 2, 3, 4]).boxed())
+            .unwrap();
+        let source = df.clone();
+        let mapping = df.label_encode("s").unwrap();
+        assert_eq!(mapping.len(), 2);
+        assert_eq!(mapping["a"], 0);
+        assert_eq!(mapping["b"], 1);
+        assert_eq!(df.fields()[0].data_type(), &DataType::Int64);
+        assert_eq!(
+            df.get_int64("s").unwrap(),
+            &Int64Array::from(vec![Some(1), None, Some(0), Some(1)])
+        );
+        let mut decoded = df.clone();
+        decoded.label_decode("s", &mapping).unwrap();
+        assert!(decoded == source);
+        let partial: HashMap<String, i64> = [("a".to_owned(), 0)].into_iter().collect();
+        df.label_decode("s", &partial).unwrap();
+        assert_eq!(
+            df.get_utf8_iter("s").unwrap().collect::<Vec<_>>(),
+            [None, None, Some("a"), None]
+        );
+        assert!(matches!(
+            df.label_decode("s", &mapping),
+            Err(Error::TypeMismatch)
+        ));
+        assert!(matches!(df.label_encode("x"), Err(Error::TypeMismatch)));
+    }
+
+    #[test]
+    fn test_cut() {
+        let df = float_df(vec![
+            Some(0.0),
+            Some(0.5),
+            Some(1.0),
+            Some(1.5),
+            Some(2.0),
+            Some(-0.1),
+            Some(f64::NAN),
+            None,
+        ]);
+        let result = df.cut("x", &[0.0, 1.0, 2.0], &["low", "high"]).unwrap();
+        assert_eq!(
+            labels(&result),
+            [
+                Some("low"),
+                Some("low"),
+                Some("high"),
+                Some("high"),
+                None,
+                None,
+                None,
+                None
+            ]
+        );
+        for (bins, labels) in [
+            (&[0.0, 1.0, 2.0][..], &["low"][..]),
+            (&[0.0, 1.0, 2.0][..], &["a", "b", "c"][..]),
+            (&[0.0][..], &[][..]),
+            (&[0.0, 1.0, 1.0][..], &["a", "b"][..]),
+            (&[0.0, 2.0, 1.0][..], &["a", "b"][..]),
+            (&[0.0, f64::NAN, 2.0][..], &["a", "b"][..]),
+        ] {
+            assert!(matches!(
+                df.cut("x", bins, labels),
+                Err(Error::InvalidArgument(_))
+            ));
+        }
+        assert!(matches!(
+            df.cut("y", &[0.0, 1.0], &["a"]),
+            Err(Error::NotFound(_))
+        ));
+    }
+    #[test]
+    fn test_qcut() {
+        let df = float_df(vec![
+            Some(5.0),
+            Some(1.0),
+            Some(3.0),
+            Some(f64::NAN),
+            Some(2.0),
+            None,
+            Some(4.0),
+        ]);
+        let result = df.qcut("x", &[0.0, 0.5, 1.0], &["q1", "q2"]).unwrap();
+        assert_eq!(
+            labels(&result),
+            [
+                Some("q2"),
+                Some("q1"),
+                Some("q2"),
+                None,
+                Some("q1"),
+                None,
+                Some("q2")
+            ]
+        );
+        let result = df.qcut("x", &[0.25, 0.75], &["mid"]).unwrap();
+        assert_eq!(
+            labels(&result),
+            [
+                None,
+                None,
+                Some("mid"),
+                None,
+                Some("mid"),
+                None,
+                Some("mid")
+            ]
+        );
+        let df = float_df(vec![Some(1.0), Some(1.0), Some(1.0), Some(2.0)]);
+        assert!(matches!(
+            df.qcut("x", &[0.0, 0.5, 1.0], &["a", "b"]),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            df.qcut("x", &[0.0, 1.5], &["a"]),
+            Err(Error::InvalidArgument(_))
+        ));
+        let df = float_df(vec![None, None]);
+        let result = df.qcut("x", &[0.0, 1.0], &["a"]).unwrap();
+        assert_eq!(labels(&result), [None, None]);
+    }
+}
@@ -0,0 +1,331 @@
+#[cfg(feature = "arrow2_ih")]
+extern crate arrow2_ih as arrow2;
+
+use crate::df::{DataFrame, Series};
+use crate::ops::counts::{groups, keys};
+use crate::Error;
+use arrow2::array::{Array, Float64Array, Int64Array, PrimitiveArray};
+use arrow2::compute;
+use arrow2::datatypes::DataType;
+use arrow2::types::NativeType;
+use std::fmt;
+
+/// Aggregation function for [`DataFrame::groupby_agg`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AggFunc {
+    /// sum of non-null values (Float64/Int64)
+    Sum,
+    /// mean of non-null values (Float64/Int64), always Float64
+    Mean,
+    /// minimum of non-null values (Float64/Int64)
+    Min,
+    /// maximum of non-null values (Float64/Int64)
+    Max,
+    /// number of rows, including nulls
+    Count,
+    /// the first non-null value
+    First,
+    /// the last non-null value
+    Last,
+}
+
+impl fmt::Display for AggFunc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                AggFunc::Sum => "sum",
+                AggFunc::Mean => "mean",
+                AggFunc::Min => "min",
+                AggFunc::Max => "max",
+                AggFunc::Count => "count",
+                AggFunc::First => "first",
+                AggFunc::Last => "last",
+            }
+        )
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn aggregate_primitive<T>(
+    values: &PrimitiveArray<T>,
+    groups: &[Vec<usize>],
+    func: AggFunc,
+    add: fn(T, T) -> Option<T>,
+    to_float: fn(T) -> f64,
+) -> Result<Series, Error>
+where
+    T: NativeType + PartialOrd,
+{
+    let group_values = |rows: &Vec<usize>| -> Vec<T> {
+        rows.iter()
+            .filter_map(|i| values.get(*i))
+            .collect::<Vec<T>>()
+    };
+    match func {
+        AggFunc::Mean => {
+            let result: Float64Array = groups
+                .iter()
+                .map(|rows| {
+                    let vals = group_values(rows);
+                    if vals.is_empty() {
+                        None
+                    } else {
+                        Some(vals.iter().map(|v| to_float(*v)).sum::<f64>() / vals.len() as f64)
+                    }
+                })
+                .collect();
+            Ok(result.boxed())
+        }
+        AggFunc::Sum | AggFunc::Min | AggFunc::Max => {
+            let mut result: Vec<Option<T>> = Vec::with_capacity(groups.len());
+            for rows in groups {
+                let mut acc: Option<T> = None;
+                for v in group_values(rows) {
+                    acc = Some(if let Some(a) = acc {
+                        match func {
+                            AggFunc::Sum => add(a, v).ok_or(Error::Overflow)?,
+                            AggFunc::Min if v < a => v,
+                            AggFunc::Max if v > a => v,
+                            _ => a,
+                        }
+                    } else {
+                        v
+                    });
+                }
+                result.push(acc);
+            }
+            Ok(PrimitiveArray::<T>::from(result).boxed())
+        }
+        _ => Err(Error::Unimplemented(func.to_string())),
+    }
+}
+
+impl DataFrame {
+    /// Group the data frame by a key column and aggregate the specified columns
+    ///
+    /// The result contains the key column (distinct values in order of the first occurrence) and
+    /// a column named {column}_{func} for each aggregation. Nulls in the key column form their own
+    /// group, null values are ignored by all functions except Count
+    #[allow(clippy::cast_possible_wrap, clippy::cast_precision_loss)]
+    pub fn groupby_agg(&self, key: &str, agg: &[(&str, AggFunc)]) -> Result<Self, Error> {
+        let (key_serie, key_data_type) = self
+            .get_series(key)
+            .ok_or_else(|| Error::NotFound(key.to_owned()))?;
+        let groups = groups(&keys(key_serie.as_ref())?);
+        let firsts = Int64Array::from_iter_values(groups.iter().map(|rows| rows[0] as i64));
+        let mut df = DataFrame::new(Some(agg.len() + 1));
+        df.add_series(
+            key,
+            compute::take::take(key_serie.as_ref(), &firsts)?,
+            Some(key_data_type.clone()),
+            None,
+        )?;
+        for (name, func) in agg {
+            let (serie, data_type) = self
+                .get_series(name)
+                .ok_or_else(|| Error::NotFound((*name).to_owned()))?;
+            let (result, data_type) = match func {
+                AggFunc::Count => (
+                    Int64Array::from_iter_values(groups.iter().map(|rows| rows.len() as i64))
+                        .boxed(),
+                    DataType::Int64,
+                ),
+                AggFunc::First | AggFunc::Last => {
+                    let indices = Int64Array::from_iter_values(groups.iter().map(|rows| {
+                        let pos = if *func == AggFunc::First {
+                            rows.iter().find(|i| serie.is_valid(**i))
+                        } else {
+                            rows.iter().rev().find(|i| serie.is_valid(**i))
+                        };
+                        *pos.unwrap_or(&rows[0]) as i64
+                    }));
+                    (
+                        compute::take::take(serie.as_ref(), &indices)?,
+                        data_type.clone(),
+                    )
+                }
+                _ => match data_type {
+                    DataType::Float64 => {
+                        let result = aggregate_primitive(
+                            self.get_float64(name)?,
+                            &groups,
+                            *func,
+                            |a, b| Some(a + b),
+                            |v| v,
+                        )?;
+                        let data_type = result.data_type().clone();
+                        (result, data_type)
+                    }
+                    DataType::Int64 => {
+                        let result = aggregate_primitive(
+                            self.get_int64(name)?,
+                            &groups,
+                            *func,
+                            i64::checked_add,
+                            |v| v as f64,
+                        )?;
+                        let data_type = result.data_type().clone();
+                        (result, data_type)
+                    }
+                    _ => return Err(Error::TypeMismatch),
+                },
+            };
+            df.add_series(&format!("{}_{}", name, func), result, Some(data_type), None)?;
+        }
+        Ok(df)
+    }
+    /// Split the data frame into sub-frames by a key column, in order of the first occurrence
+    ///
+    /// The group keys are the key values formatted as strings, None for the null key. Each
+    /// sub-frame is a copy, so the memory usage is about the size of the source data frame
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn groupby_collect(&self, key: &str) -> Result<Vec<(Option<String>, Self)>, Error> {
+        let (key_serie, _) = self
+            .get_series(key)
+            .ok_or_else(|| Error::NotFound(key.to_owned()))?;
+        let display = arrow2::array::get_display(key_serie.as_ref(), "");
+        let mut result = Vec::new();
+        for rows in groups(&keys(key_serie.as_ref())?) {
+            let group_key = if key_serie.is_valid(rows[0]) {
+                let mut s = String::new();
+                display(&mut s, rows[0])?;
+                Some(s)
+            } else {
+                None
+            };
+            let indices = Int64Array::from_iter_values(rows.into_iter().map(|i| i as i64));
+            let data = self
+                .data()
+                .iter()
+                .map(|serie| compute::take::take(serie.as_ref(), &indices))
+                .collect::<Result<Vec<Series>, _>>()?;
+            result.push((
+                group_key,
+                DataFrame::from_parts(self.fields().to_vec(), data, Some(self.metadata().clone()))?,
+            ));
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow2::array::Utf8Array;
+
+    fn sample_df() -> DataFrame {
+        let mut df = DataFrame::new0();
+        df.add_series0(
+            "k",
+            Utf8Array::<i32>::from(vec![Some("a"), Some("b"), None, Some("a"), Some("b")]).boxed(),
+        )
+        .unwrap();
+        df.add_series0(
+            "v",
+            Float64Array::from(vec![Some(1.0), None, Some(3.0), Some(2.0), Some(5.0)]).boxed(),
+        )
+        .unwrap();
+        df.add_series0(
+            "i",
+            Int64Array::from(vec![Some(4), Some(7), None, None, Some(1)]).boxed(),
+        )
+        .unwrap();
+        df
+    }
+
+    #[test]
+    fn test_groupby_agg() {
+        let df = sample_df();
+        let result = df
+            .groupby_agg(
+                "k",
+                &[
+                    ("v", AggFunc::Sum),
+                    ("v", AggFunc::Count),
+                    ("v", AggFunc::Mean),
+                    ("i", AggFunc::Max),
+                    ("i", AggFunc::Last),
+                ],
+            )
+            .unwrap();
+        assert_eq!(
+            result.names(),
+            ["k", "v_sum", "v_count", "v_mean", "i_max", "i_last"]
+        );
+        assert_eq!(
+            result.get_utf8_iter("k").unwrap().collect::<Vec<_>>(),
+            [Some("a"), Some("b"), None]
+        );
+        assert_eq!(
+            result.get_float64("v_sum").unwrap(),
+            &Float64Array::from(vec![Some(3.0), Some(5.0), Some(3.0)])
+        );
+        assert_eq!(
+            result.get_int64("v_count").unwrap(),
+            &Int64Array::from_vec(vec![2, 2, 1])
+        );
+        assert_eq!(
+            result.get_float64("v_mean").unwrap(),
+            &Float64Array::from(vec![Some(1.5), Some(5.0), Some(3.0)])
+        );
+        assert_eq!(
+            result.get_int64("i_max").unwrap(),
+            &Int64Array::from(vec![Some(4), Some(7), None])
+        );
+        assert_eq!(
+            result.get_int64("i_last").unwrap(),
+            &Int64Array::from(vec![Some(4), Some(1), None])
+        );
+        assert!(matches!(
+            df.groupby_agg("x", &[("v", AggFunc::Sum)]),
+            Err(Error::NotFound(_))
+        ));
+        assert!(matches!(
+            df.groupby_agg("k", &[("k", AggFunc::Sum)]),
+            Err(Error::TypeMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_groupby_collect() {
+        let mut df = DataFrame::new0();
+        df.add_series0(
+            "k",
+            Int64Array::from(vec![Some(2), Some(1), None, Some(2), Some(1)]).boxed(),
+        )
+        .unwrap();
+        df.add_series0(
+            "v",
+            Utf8Array::<i32>::from_slice(["a", "b", "c", "d", "e"]).boxed(),
+        )
+        .unwrap();
+        df.set_metadata_field("source", "test");
+        df.set_col_metadata_field("v", "unit", "m").unwrap();
+        let groups = df.groupby_collect("k").unwrap();
+        let keys: Vec<Option<&str>> = groups.iter().map(|(k, _)| k.as_deref()).collect();
+        assert_eq!(keys, [Some("2"), Some("1"), None]);
+        let values: Vec<Vec<Option<&str>>> = groups
+            .iter()
+            .map(|(_, group)| group.get_utf8_iter("v").unwrap().collect())
+            .collect();
+        assert_eq!(
+            values,
+            [
+                vec![Some("a"), Some("d")],
+                vec![Some("b"), Some("e")],
+                vec![Some("c")]
+            ]
+        );
+        for (_, group) in &groups {
+            assert_eq!(group.fields(), df.fields());
+            assert_eq!(group.metadata(), df.metadata());
+        }
+        let groups = df.groupby_collect("v").unwrap();
+        assert_eq!(groups.len(), 5);
+        assert_eq!(groups[0].0.as_deref(), Some("a"));
+        assert!(groups[0].1 == df.try_sliced(0, 1).unwrap());
+        assert!(matches!(df.groupby_collect("x"), Err(Error::NotFound(_))));
+    }
+}
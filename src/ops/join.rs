@@ -0,0 +1,244 @@
+#[cfg(feature = "arrow2_ih")]
+extern crate arrow2_ih as arrow2;
+
+use crate::df::DataFrame;
+use crate::ops::counts::{keys, Key};
+use crate::Error;
+use arrow2::array::{Array, Int64Array};
+use arrow2::compute;
+use std::collections::HashMap;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum JoinKind {
+    Inner,
+    Left,
+    Outer,
+}
+
+impl DataFrame {
+    /// Inner join two data frames on a key column
+    ///
+    /// Only rows with keys present in both frames are returned, duplicate keys produce all
+    /// combinations of rows. Right columns, which names conflict with the left ones, get "_right"
+    /// suffix. Null keys are never matched
+    #[inline]
+    pub fn inner_join(&self, other: &DataFrame, on: &str) -> Result<Self, Error> {
+        self.join_on(other, on, JoinKind::Inner)
+    }
+    /// Left join two data frames on a key column
+    ///
+    /// All left rows are returned, right columns are filled with nulls for rows with no match.
+    /// See [`DataFrame::inner_join`] for duplicate keys and column names handling
+    #[inline]
+    pub fn left_join(&self, other: &DataFrame, on: &str) -> Result<Self, Error> {
+        self.join_on(other, on, JoinKind::Left)
+    }
+    /// Full outer join two data frames on a key column
+    ///
+    /// All rows of both frames are returned, columns of the missing side are filled with nulls.
+    /// See [`DataFrame::inner_join`] for duplicate keys and column names handling
+    #[inline]
+    pub fn outer_join(&self, other: &DataFrame, on: &str) -> Result<Self, Error> {
+        self.join_on(other, on, JoinKind::Outer)
+    }
+    #[allow(clippy::cast_possible_wrap)]
+    fn join_on(&self, other: &DataFrame, on: &str, kind: JoinKind) -> Result<Self, Error> {
+        let left_pos = self.column_index(on)?;
+        let right_pos = other
+            .get_column_index(on)
+            .ok_or_else(|| Error::NotFound(on.to_owned()))?;
+        let left_key = self.data()[left_pos].as_ref();
+        let right_key = other.data()[right_pos].as_ref();
+        if left_key.data_type() != right_key.data_type() {
+            return Err(Error::TypeMismatch);
+        }
+        let mut right_rows: HashMap<Key, Vec<usize>> = HashMap::new();
+        for (i, key) in keys(right_key)?.into_iter().enumerate() {
+            if key != Key::Null {
+                right_rows.entry(key).or_default().push(i);
+            }
+        }
+        let mut left_indices: Vec<Option<i64>> = Vec::new();
+        let mut right_indices: Vec<Option<i64>> = Vec::new();
+        let mut right_matched = vec![false; right_key.len()];
+        for (i, key) in keys(left_key)?.into_iter().enumerate() {
+            if let Some(rows) = right_rows.get(&key) {
+                for r in rows {
+                    left_indices.push(Some(i as i64));
+                    right_indices.push(Some(*r as i64));
+                    right_matched[*r] = true;
+                }
+            } else if kind != JoinKind::Inner {
+                left_indices.push(Some(i as i64));
+                right_indices.push(None);
+            }
+        }
+        // the key column, taken from the right side for unmatched right rows
+        let key_serie = if kind == JoinKind::Outer {
+            let key_indices: Vec<Option<i64>> = left_indices
+                .iter()
+                .copied()
+                .chain(
+                    right_matched
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, matched)| !**matched)
+                        .map(|(r, _)| Some((left_key.len() + r) as i64)),
+                )
+                .collect();
+            for (r, matched) in right_matched.iter().enumerate() {
+                if !matched {
+                    left_indices.push(None);
+                    right_indices.push(Some(r as i64));
+                }
+            }
+            let keys = compute::concatenate::concatenate(&[left_key, right_key])?;
+            compute::take::take(keys.as_ref(), &Int64Array::from(key_indices))?
+        } else {
+            compute::take::take(left_key, &Int64Array::from(left_indices.as_slice()))?
+        };
+        let mut df = DataFrame::new(Some(self.fields().len() + other.fields().len() - 1));
+        let left_indices = Int64Array::from(left_indices);
+        let right_indices = Int64Array::from(right_indices);
+        for (i, (field, serie)) in self.fields().iter().zip(self.data()).enumerate() {
+            let series = if i == left_pos {
+                key_serie.clone()
+            } else {
+                compute::take::take(serie.as_ref(), &left_indices)?
+            };
+            df.add_series(
+                &field.name,
+                series,
+                Some(field.data_type().clone()),
+                Some(field.metadata.clone()),
+            )?;
+        }
+        for (i, (field, serie)) in other.fields().iter().zip(other.data()).enumerate() {
+            if i == right_pos {
+                continue;
+            }
+            let name = if self.get_column_index(&field.name).is_some() {
+                format!("{}_right", field.name)
+            } else {
+                field.name.clone()
+            };
+            df.add_series(
+                &name,
+                compute::take::take(serie.as_ref(), &right_indices)?,
+                Some(field.data_type().clone()),
+                Some(field.metadata.clone()),
+            )?;
+        }
+        df.set_metadata(self.metadata().clone());
+        Ok(df)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow2::array::Utf8Array;
+
+    fn join_df(ids: Vec<Option<i64>>, names: Vec<&str>) -> DataFrame {
+        let mut df = DataFrame::new0();
+        df.add_series0("id", Int64Array::from(ids).boxed()).unwrap();
+        df.add_series0("name", Utf8Array::<i32>::from_slice(names).boxed())
+            .unwrap();
+        df
+    }
+    fn sample_frames() -> (DataFrame, DataFrame) {
+        (
+            join_df(
+                vec![Some(1), Some(2), Some(3), None],
+                vec!["a", "b", "c", "d"],
+            ),
+            join_df(
+                vec![Some(2), Some(2), Some(3), Some(4), None],
+                vec!["x", "y", "z", "w", "n"],
+            ),
+        )
+    }
+
+    #[test]
+    fn test_inner_join() {
+        let (left, right) = sample_frames();
+        let df = left.inner_join(&right, "id").unwrap();
+        assert_eq!(df.names(), ["id", "name", "name_right"]);
+        assert_eq!(
+            df.get_int64("id").unwrap(),
+            &Int64Array::from_vec(vec![2, 2, 3])
+        );
+        assert_eq!(
+            df.get_utf8_iter("name").unwrap().collect::<Vec<_>>(),
+            [Some("b"), Some("b"), Some("c")]
+        );
+        assert_eq!(
+            df.get_utf8_iter("name_right").unwrap().collect::<Vec<_>>(),
+            [Some("x"), Some("y"), Some("z")]
+        );
+        let df = left
+            .inner_join(&join_df(vec![Some(5)], vec!["e"]), "id")
+            .unwrap();
+        assert_eq!(df.rows(), Some(0));
+        assert!(matches!(
+            left.inner_join(&right, "x"),
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_left_outer_join() {
+        let (left, right) = sample_frames();
+        let df = left.left_join(&right, "id").unwrap();
+        assert_eq!(df.names(), ["id", "name", "name_right"]);
+        assert_eq!(
+            df.get_int64("id").unwrap(),
+            &Int64Array::from(vec![Some(1), Some(2), Some(2), Some(3), None])
+        );
+        assert_eq!(
+            df.get_utf8_iter("name").unwrap().collect::<Vec<_>>(),
+            [Some("a"), Some("b"), Some("b"), Some("c"), Some("d")]
+        );
+        assert_eq!(
+            df.get_utf8_iter("name_right").unwrap().collect::<Vec<_>>(),
+            [None, Some("x"), Some("y"), Some("z"), None]
+        );
+        let df = left.outer_join(&right, "id").unwrap();
+        assert_eq!(
+            df.get_int64("id").unwrap(),
+            &Int64Array::from(vec![
+                Some(1),
+                Some(2),
+                Some(2),
+                Some(3),
+                None,
+                Some(4),
+                None
+            ])
+        );
+        assert_eq!(
+            df.get_utf8_iter("name").unwrap().collect::<Vec<_>>(),
+            [
+                Some("a"),
+                Some("b"),
+                Some("b"),
+                Some("c"),
+                Some("d"),
+                None,
+                None
+            ]
+        );
+        assert_eq!(
+            df.get_utf8_iter("name_right").unwrap().collect::<Vec<_>>(),
+            [
+                None,
+                Some("x"),
+                Some("y"),
+                Some("z"),
+                None,
+                Some("w"),
+                Some("n")
+            ]
+        );
+    }
+}
@@ -0,0 +1,243 @@
+#[cfg(feature = "arrow2_ih")]
+extern crate arrow2_ih as arrow2;
+
+use crate::df::DataFrame;
+use crate::Error;
+use arrow2::array::{Array, BooleanArray};
+use arrow2::compute::boolean;
+use arrow2::compute::comparison::primitive;
+use arrow2::compute::if_then_else::if_then_else;
+
+impl DataFrame {
+    /// Mask of Float64 column values greater than the threshold, nulls remain null
+    #[inline]
+    pub fn mask_gt(&self, name: &str, threshold: f64) -> Result<BooleanArray, Error> {
+        Ok(primitive::gt_scalar(self.get_float64(name)?, threshold))
+    }
+    /// Mask of Float64 column values less than the threshold, nulls remain null
+    #[inline]
+    pub fn mask_lt(&self, name: &str, threshold: f64) -> Result<BooleanArray, Error> {
+        Ok(primitive::lt_scalar(self.get_float64(name)?, threshold))
+    }
+    /// Mask of Float64 column values greater than or equal to the threshold, nulls remain null
+    #[inline]
+    pub fn mask_ge(&self, name: &str, threshold: f64) -> Result<BooleanArray, Error> {
+        Ok(primitive::gt_eq_scalar(self.get_float64(name)?, threshold))
+    }
+    /// Mask of Float64 column values less than or equal to the threshold, nulls remain null
+    #[inline]
+    pub fn mask_le(&self, name: &str, threshold: f64) -> Result<BooleanArray, Error> {
+        Ok(primitive::lt_eq_scalar(self.get_float64(name)?, threshold))
+    }
+    /// Mask of Float64 column values equal to the value, nulls remain null
+    #[inline]
+    pub fn mask_eq_float(&self, name: &str, value: f64) -> Result<BooleanArray, Error> {
+        Ok(primitive::eq_scalar(self.get_float64(name)?, value))
+    }
+    /// Keep rows where the mask is true, alias for [`DataFrame::filter`]
+    #[inline]
+    pub fn filter_by_mask(&self, mask: &BooleanArray) -> Result<Self, Error> {
+        self.filter(mask)
+    }
+    /// Create a new data frame with rows where a Float64 column value is in [lo, hi]
+    ///
+    /// Rows with null values are excluded
+    pub fn filter_by_float_range(&self, name: &str, lo: f64, hi: f64) -> Result<Self, Error> {
+        if lo > hi {
+            return Err(Error::InvalidArgument(format!(
+                "lo ({}) is greater than hi ({})",
+                lo, hi
+            )));
+        }
+        let values = self.get_float64(name)?;
+        let mask = boolean::and(
+            &primitive::gt_eq_scalar(values, lo),
+            &primitive::lt_eq_scalar(values, hi),
+        );
+        self.filter(&mask)
+    }
+    /// Add a new column, taking values of true_col where the condition is true and false_col
+    /// where it is false. Null conditions produce nulls
+    ///
+    /// The source columns must have the same type
+    pub fn conditional_column(
+        &mut self,
+        condition: &BooleanArray,
+        true_col: &str,
+        false_col: &str,
+        dest: &str,
+    ) -> Result<(), Error> {
+        let (a, a_data_type) = self
+            .get_series(true_col)
+            .ok_or_else(|| Error::NotFound(true_col.to_owned()))?;
+        let (b, b_data_type) = self
+            .get_series(false_col)
+            .ok_or_else(|| Error::NotFound(false_col.to_owned()))?;
+        if a_data_type != b_data_type || a.data_type() != b.data_type() {
+            return Err(Error::TypeMismatch);
+        }
+        if condition.len() != a.len() {
+            return Err(Error::RowsNotMatch);
+        }
+        let data_type = a_data_type.clone();
+        let mut result = if_then_else(condition, a.as_ref(), b.as_ref())?;
+        if let Some(cond_validity) = condition.validity() {
+            let validity = match result.validity() {
+                Some(validity) => validity & cond_validity,
+                None => cond_validity.clone(),
+            };
+            result = result.with_validity(Some(validity));
+        }
+        self.add_series(dest, result, Some(data_type), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow2::array::{Float64Array, Int64Array};
+
+    fn float_df(values: Vec<Option<f64>>) -> DataFrame {
+        let mut df = DataFrame::new0();
+        df.add_series0("x", Float64Array::from(values).boxed())
+            .unwrap();
+        df
+    }
+
+    #[test]
+    fn test_masks() {
+        let df = float_df(vec![Some(1.0), Some(2.0), None, Some(3.0), Some(2.0)]);
+        let mask = df.mask_gt("x", 1.5).unwrap();
+        assert_eq!(mask.len(), 5);
+        assert_eq!(
+            mask.iter().collect::<Vec<_>>(),
+            vec![Some(false), Some(true), None, Some(true), Some(true)]
+        );
+        let inverted = boolean::not(&mask);
+        let selected = df.filter_by_mask(&mask).unwrap();
+        let rest = df.filter_by_mask(&inverted).unwrap();
+        assert_eq!(selected.rows(), Some(3));
+        assert_eq!(rest.rows(), Some(1));
+        assert_eq!(
+            selected.rows().unwrap() + rest.rows().unwrap() + df.null_count("x").unwrap(),
+            df.rows().unwrap()
+        );
+        assert_eq!(
+            df.mask_lt("x", 2.0)
+                .unwrap()
+                .iter()
+                .flatten()
+                .filter(|v| *v)
+                .count(),
+            1
+        );
+        assert_eq!(
+            df.mask_le("x", 2.0)
+                .unwrap()
+                .iter()
+                .flatten()
+                .filter(|v| *v)
+                .count(),
+            3
+        );
+        assert_eq!(
+            df.mask_ge("x", 2.0)
+                .unwrap()
+                .iter()
+                .flatten()
+                .filter(|v| *v)
+                .count(),
+            3
+        );
+        let eq = df.mask_eq_float("x", 2.0).unwrap();
+        assert_eq!(
+            eq.iter().collect::<Vec<_>>(),
+            vec![Some(false), Some(true), None, Some(false), Some(true)]
+        );
+        assert!(matches!(df.mask_gt("y", 0.0), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn test_filter_by_float_range() {
+        let df = float_df(vec![
+            Some(0.5),
+            Some(1.0),
+            None,
+            Some(1.5),
+            Some(2.0),
+            Some(2.5),
+        ]);
+        let filtered = df.filter_by_float_range("x", 1.0, 2.0).unwrap();
+        assert_eq!(
+            filtered.get_float64("x").unwrap().values().as_slice(),
+            &[1.0, 1.5, 2.0]
+        );
+        let filtered = df.filter_by_float_range("x", 2.0, 2.0).unwrap();
+        assert_eq!(
+            filtered.get_float64("x").unwrap().values().as_slice(),
+            &[2.0]
+        );
+        assert_eq!(
+            df.filter_by_float_range("x", 3.0, 4.0).unwrap().rows(),
+            Some(0)
+        );
+        assert_eq!(
+            df.filter_by_float_range("x", f64::MIN, f64::MAX)
+                .unwrap()
+                .rows(),
+            Some(5)
+        );
+        assert!(matches!(
+            df.filter_by_float_range("x", 2.0, 1.0),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_conditional_column() {
+        let mut df = float_df(vec![Some(1.0), Some(2.0), None, Some(4.0)]);
+        df.add_series0(
+            "y",
+            Float64Array::from(vec![Some(-1.0), None, Some(-3.0), Some(-4.0)]).boxed(),
+        )
+        .unwrap();
+        df.conditional_column(&BooleanArray::from_slice([true; 4]), "x", "y", "all_true")
+            .unwrap();
+        df.conditional_column(&BooleanArray::from_slice([false; 4]), "x", "y", "all_false")
+            .unwrap();
+        let mixed = BooleanArray::from(vec![Some(true), Some(false), None, Some(false)]);
+        df.conditional_column(&mixed, "x", "y", "mixed").unwrap();
+        assert_eq!(
+            df.get_float64("all_true").unwrap(),
+            df.get_float64("x").unwrap()
+        );
+        assert_eq!(
+            df.get_float64("all_false").unwrap(),
+            df.get_float64("y").unwrap()
+        );
+        assert_eq!(
+            df.get_float64("mixed").unwrap(),
+            &Float64Array::from(vec![Some(1.0), None, None, Some(-4.0)])
+        );
+        let mask = BooleanArray::from(vec![None, Some(true), Some(false), Some(true)]);
+        df.conditional_column(&mask, "y", "x", "nulls").unwrap();
+        assert_eq!(
+            df.get_float64("nulls").unwrap(),
+            &Float64Array::from(vec![None, None, None, Some(-4.0)])
+        );
+        df.add_series0("i", Int64Array::from_vec(vec![1, 2, 3, 4]).boxed())
+            .unwrap();
+        assert!(matches!(
+            df.conditional_column(&mixed, "x", "i", "z"),
+            Err(Error::TypeMismatch)
+        ));
+        assert!(matches!(
+            df.conditional_column(&BooleanArray::from_slice([true]), "x", "y", "z"),
+            Err(Error::RowsNotMatch)
+        ));
+        assert!(matches!(
+            df.conditional_column(&mixed, "x", "y", "x"),
+            Err(Error::AlreadyExists(_))
+        ));
+    }
+}
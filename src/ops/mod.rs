@@ -1 +1,13 @@
 pub(crate) mod concat;
+pub(crate) mod counts;
+pub(crate) mod encode;
+pub(crate) mod group;
+pub(crate) mod join;
+pub(crate) mod mask;
+pub(crate) mod rank;
+pub(crate) mod reshape;
+pub(crate) mod stats;
+pub(crate) mod strings;
+pub(crate) mod time;
+pub(crate) mod transform;
+pub(crate) mod window;
@@ -0,0 +1,124 @@
+#[cfg(feature = "arrow2_ih")]
+extern crate arrow2_ih as arrow2;
+
+use crate::df::{DataFrame, Series};
+use crate::Error;
+use arrow2::array::Float64Array;
+
+/// Tie-breaking method for [`DataFrame::rank_column`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum RankMethod {
+    /// average rank of the group
+    #[default]
+    Average,
+    /// lowest rank of the group
+    Min,
+    /// highest rank of the group
+    Max,
+    /// ranks in order of appearance
+    First,
+    /// like Min, but ranks always increase by 1 between groups
+    Dense,
+}
+
+impl DataFrame {
+    /// Rank values of a Float64 column (1-based)
+    ///
+    /// Nulls are ranked as a single group, placed last if nulls_last is true, otherwise first
+    #[allow(clippy::cast_precision_loss)]
+    pub fn rank_column(
+        &self,
+        name: &str,
+        method: RankMethod,
+        nulls_last: bool,
+    ) -> Result<Series, Error> {
+        let values = self.get_float64(name)?;
+        let mut order: Vec<usize> = (0..values.len()).collect();
+        order.sort_by(|a, b| match (values.get(*a), values.get(*b)) {
+            (Some(x), Some(y)) => x.total_cmp(&y),
+            (None, None) => std::cmp::Ordering::Equal,
+            (None, Some(_)) if nulls_last => std::cmp::Ordering::Greater,
+            (None, Some(_)) => std::cmp::Ordering::Less,
+            (Some(_), None) if nulls_last => std::cmp::Ordering::Less,
+            (Some(_), None) => std::cmp::Ordering::Greater,
+        });
+        let mut ranks = vec![0.0; values.len()];
+        let mut start = 0;
+        let mut group: u32 = 0;
+        while start < order.len() {
+            let first = values.get(order[start]);
+            let mut end = start + 1;
+            while end < order.len()
+                && match (first, values.get(order[end])) {
+                    (Some(x), Some(y)) => x.total_cmp(&y).is_eq(),
+                    (None, None) => true,
+                    _ => false,
+                }
+            {
+                end += 1;
+            }
+            group += 1;
+            for (pos, i) in order[start..end].iter().enumerate() {
+                ranks[*i] = match method {
+                    RankMethod::Average => (start + end + 1) as f64 / 2.0,
+                    RankMethod::Min => (start + 1) as f64,
+                    RankMethod::Max => end as f64,
+                    RankMethod::First => (start + pos + 1) as f64,
+                    RankMethod::Dense => f64::from(group),
+                };
+            }
+            start = end;
+        }
+        Ok(Float64Array::from_vec(ranks).boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranks(serie: &Series) -> Vec<f64> {
+        let values: &Float64Array = serie.as_any().downcast_ref().unwrap();
+        values.values().to_vec()
+    }
+
+    #[test]
+    fn test_rank_column() {
+        let mut df = DataFrame::new0();
+        df.add_series0(
+            "x",
+            Float64Array::from(vec![
+                Some(2.0),
+                Some(1.0),
+                Some(2.0),
+                None,
+                Some(2.0),
+                Some(3.0),
+            ])
+            .boxed(),
+        )
+        .unwrap();
+        for (method, expected) in [
+            (RankMethod::Average, [3.0, 1.0, 3.0, 6.0, 3.0, 5.0]),
+            (RankMethod::Min, [2.0, 1.0, 2.0, 6.0, 2.0, 5.0]),
+            (RankMethod::Max, [4.0, 1.0, 4.0, 6.0, 4.0, 5.0]),
+            (RankMethod::First, [2.0, 1.0, 3.0, 6.0, 4.0, 5.0]),
+            (RankMethod::Dense, [2.0, 1.0, 2.0, 4.0, 2.0, 3.0]),
+        ] {
+            assert_eq!(ranks(&df.rank_column("x", method, true).unwrap()), expected);
+        }
+        assert_eq!(
+            ranks(&df.rank_column("x", RankMethod::Average, false).unwrap()),
+            [4.0, 2.0, 4.0, 1.0, 4.0, 6.0]
+        );
+        assert_eq!(
+            ranks(&df.rank_column("x", RankMethod::Dense, false).unwrap()),
+            [3.0, 2.0, 3.0, 1.0, 3.0, 4.0]
+        );
+        assert_eq!(RankMethod::default(), RankMethod::Average);
+        assert!(matches!(
+            df.rank_column("y", RankMethod::Min, true),
+            Err(Error::NotFound(_))
+        ));
+    }
+}
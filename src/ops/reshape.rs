@@ -0,0 +1,323 @@
+#[cfg(feature = "arrow2_ih")]
+extern crate arrow2_ih as arrow2;
+
+use crate::df::DataFrame;
+use crate::ops::counts::{keys, Key};
+use crate::Error;
+use arrow2::array::{Array, Float64Array, Int64Array, Utf8Array};
+use arrow2::compute;
+use arrow2::datatypes::DataType;
+use std::collections::HashMap;
+
+impl DataFrame {
+    /// Pivot the data frame: distinct values of the index column become rows, distinct values of
+    /// the columns column become Float64 columns, filled with values of the values column
+    /// (Float64 or Int64)
+    ///
+    /// Multiple values of the same (index, column) combination are averaged, missing combinations
+    /// produce nulls. The rows and the columns are ordered by the first occurrence, rows with
+    /// nulls in the columns column are skipped
+    #[allow(clippy::cast_possible_wrap, clippy::cast_precision_loss)]
+    pub fn pivot(&self, index: &str, columns: &str, values: &str) -> Result<Self, Error> {
+        let (index_serie, index_data_type) = self
+            .get_series(index)
+            .ok_or_else(|| Error::NotFound(index.to_owned()))?;
+        let (columns_serie, _) = self
+            .get_series(columns)
+            .ok_or_else(|| Error::NotFound(columns.to_owned()))?;
+        let (_, values_data_type) = self
+            .get_series(values)
+            .ok_or_else(|| Error::NotFound(values.to_owned()))?;
+        let cell_values: Vec<Option<f64>> = match values_data_type {
+            DataType::Float64 => self
+                .get_float64(values)?
+                .iter()
+                .map(|v| v.copied())
+                .collect(),
+            DataType::Int64 => self
+                .get_int64(values)?
+                .iter()
+                .map(|v| v.map(|n| *n as f64))
+                .collect(),
+            _ => return Err(Error::TypeMismatch),
+        };
+        let display = arrow2::array::get_display(columns_serie.as_ref(), "");
+        let mut rows: HashMap<Key, usize> = HashMap::new();
+        let mut row_firsts: Vec<i64> = Vec::new();
+        let mut cols: HashMap<Key, usize> = HashMap::new();
+        let mut col_names: Vec<String> = Vec::new();
+        // sum and count per column per row
+        let mut cells: Vec<Vec<(f64, usize)>> = Vec::new();
+        let index_keys = keys(index_serie.as_ref())?;
+        let column_keys = keys(columns_serie.as_ref())?;
+        for (i, (index_key, column_key)) in index_keys.iter().zip(&column_keys).enumerate() {
+            if *column_key == Key::Null {
+                continue;
+            }
+            let row = *rows.entry(*index_key).or_insert_with(|| {
+                row_firsts.push(i as i64);
+                row_firsts.len() - 1
+            });
+            let col = if let Some(col) = cols.get(column_key) {
+                *col
+            } else {
+                let mut name = String::new();
+                display(&mut name, i)?;
+                col_names.push(name);
+                cells.push(Vec::new());
+                cols.insert(*column_key, cells.len() - 1);
+                cells.len() - 1
+            };
+            if cells[col].len() <= row {
+                cells[col].resize(row + 1, (0.0, 0));
+            }
+            if let Some(v) = cell_values[i] {
+                cells[col][row].0 += v;
+                cells[col][row].1 += 1;
+            }
+        }
+        let mut df = DataFrame::new(Some(col_names.len() + 1));
+        df.add_series(
+            index,
+            compute::take::take(
+                index_serie.as_ref(),
+                &Int64Array::from_vec(row_firsts.clone()),
+            )?,
+            Some(index_data_type.clone()),
+            None,
+        )?;
+        for (name, col) in col_names.iter().zip(cells) {
+            let result: Float64Array = (0..row_firsts.len())
+                .map(|row| {
+                    col.get(row)
+                        .filter(|(_, count)| *count > 0)
+                        .map(|(sum, count)| sum / *count as f64)
+                })
+                .collect();
+            df.add_series(name, result.boxed(), Some(DataType::Float64), None)?;
+        }
+        Ok(df)
+    }
+    /// Unpivot the data frame from wide to long format
+    ///
+    /// Each row produces a row per each value column, containing the id columns, the value column
+    /// name (Utf8 var_name column) and its value (value_name column). All value columns must have
+    /// the same type. If value_vars is empty, all columns except id_vars are used
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn melt(
+        &self,
+        id_vars: &[&str],
+        value_vars: &[&str],
+        var_name: &str,
+        value_name: &str,
+    ) -> Result<Self, Error> {
+        let value_vars: Vec<&str> = if value_vars.is_empty() {
+            self.names()
+                .into_iter()
+                .filter(|n| !id_vars.contains(n))
+                .collect()
+        } else {
+            value_vars.to_vec()
+        };
+        let mut value_series: Vec<&dyn Array> = Vec::with_capacity(value_vars.len());
+        for name in &value_vars {
+            let (serie, _) = self
+                .get_series(name)
+                .ok_or_else(|| Error::NotFound((*name).to_owned()))?;
+            if let Some(first) = value_series.first() {
+                if first.data_type() != serie.data_type() {
+                    return Err(Error::TypeMismatch);
+                }
+            }
+            value_series.push(serie.as_ref());
+        }
+        let rows = self.rows().unwrap_or_default();
+        let n = value_vars.len();
+        let id_indices = Int64Array::from_iter_values((0..rows * n).map(|k| (k / n) as i64));
+        let value_indices =
+            Int64Array::from_iter_values((0..rows * n).map(|k| ((k % n) * rows + k / n) as i64));
+        let mut df = DataFrame::new(Some(id_vars.len() + 2));
+        for name in id_vars {
+            let pos = self.column_index(name)?;
+            let field = &self.fields()[pos];
+            df.add_series(
+                name,
+                compute::take::take(self.data()[pos].as_ref(), &id_indices)?,
+                Some(field.data_type().clone()),
+                Some(field.metadata.clone()),
+            )?;
+        }
+        let vars: Utf8Array<i32> = (0..rows * n).map(|k| Some(value_vars[k % n])).collect();
+        df.add_series(var_name, vars.boxed(), Some(DataType::Utf8), None)?;
+        let values = if value_series.is_empty() {
+            Float64Array::new_empty(DataType::Float64).boxed()
+        } else {
+            let all = compute::concatenate::concatenate(&value_series)?;
+            compute::take::take(all.as_ref(), &value_indices)?
+        };
+        df.add_series0(value_name, values)?;
+        Ok(df)
+    }
+    /// Transpose the data frame: rows become columns, named col_0, col_1 etc.
+    ///
+    /// All columns must have the same type
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn transpose(&self) -> Result<Self, Error> {
+        let data = self.data();
+        if let Some(first) = data.first() {
+            if data.iter().any(|d| d.data_type() != first.data_type()) {
+                return Err(Error::TypeMismatch);
+            }
+            let rows = first.len();
+            let cols = data.len();
+            let all = compute::concatenate::concatenate(
+                &data.iter().map(AsRef::as_ref).collect::<Vec<&dyn Array>>(),
+            )?;
+            let mut df = DataFrame::new(Some(rows));
+            for r in 0..rows {
+                let indices =
+                    Int64Array::from_iter_values((0..cols).map(|c| (c * rows + r) as i64));
+                df.add_series0(
+                    &format!("col_{}", r),
+                    compute::take::take(all.as_ref(), &indices)?,
+                )?;
+            }
+            Ok(df)
+        } else {
+            Ok(DataFrame::new0())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn long_df(ids: Vec<&str>, vars: Vec<&str>, values: Vec<Option<f64>>) -> DataFrame {
+        let mut df = DataFrame::new0();
+        df.add_series0("id", Utf8Array::<i32>::from_slice(ids).boxed())
+            .unwrap();
+        df.add_series0("var", Utf8Array::<i32>::from_slice(vars).boxed())
+            .unwrap();
+        df.add_series0("val", Float64Array::from(values).boxed())
+            .unwrap();
+        df
+    }
+
+    #[test]
+    fn test_pivot() {
+        let df = long_df(
+            vec!["r1", "r1", "r1", "r2", "r2", "r3", "r3", "r1"],
+            vec!["a", "b", "c", "a", "c", "b", "c", "a"],
+            [1.0, 2.0, 3.0, 4.0, 6.0, 8.0, 9.0, 3.0]
+                .iter()
+                .map(|v| Some(*v))
+                .collect(),
+        );
+        let pivoted = df.pivot("id", "var", "val").unwrap();
+        assert_eq!(pivoted.names(), ["id", "a", "b", "c"]);
+        assert_eq!(
+            pivoted.get_utf8_iter("id").unwrap().collect::<Vec<_>>(),
+            [Some("r1"), Some("r2"), Some("r3")]
+        );
+        assert_eq!(
+            pivoted.get_float64("a").unwrap(),
+            &Float64Array::from(vec![Some(2.0), Some(4.0), None])
+        );
+        assert_eq!(
+            pivoted.get_float64("b").unwrap(),
+            &Float64Array::from(vec![Some(2.0), None, Some(8.0)])
+        );
+        assert_eq!(
+            pivoted.get_float64("c").unwrap(),
+            &Float64Array::from(vec![Some(3.0), Some(6.0), Some(9.0)])
+        );
+        let melted = pivoted
+            .melt(&["id"], &[], "var", "val")
+            .unwrap()
+            .drop_nulls();
+        let expected = long_df(
+            vec!["r1", "r1", "r1", "r2", "r2", "r3", "r3"],
+            vec!["a", "b", "c", "a", "c", "b", "c"],
+            [2.0, 2.0, 3.0, 4.0, 6.0, 8.0, 9.0]
+                .iter()
+                .map(|v| Some(*v))
+                .collect(),
+        );
+        assert!(melted == expected);
+        assert!(matches!(
+            df.pivot("id", "var", "var"),
+            Err(Error::TypeMismatch)
+        ));
+        assert!(matches!(
+            df.pivot("id", "x", "val"),
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_melt() {
+        let mut wide = DataFrame::new0();
+        wide.add_series0("id", Utf8Array::<i32>::from_slice(["p", "q"]).boxed())
+            .unwrap();
+        wide.add_series0("a", Float64Array::from(vec![Some(1.0), None]).boxed())
+            .unwrap();
+        wide.add_series0("b", Float64Array::from_vec(vec![3.0, 4.0]).boxed())
+            .unwrap();
+        let melted = wide.melt(&["id"], &["a", "b"], "var", "val").unwrap();
+        let expected = long_df(
+            vec!["p", "p", "q", "q"],
+            vec!["a", "b", "a", "b"],
+            vec![Some(1.0), Some(3.0), None, Some(4.0)],
+        );
+        assert!(melted == expected);
+        assert!(wide.melt(&["id"], &[], "var", "val").unwrap() == expected);
+        assert!(melted.pivot("id", "var", "val").unwrap() == wide);
+        let mut ints = DataFrame::new0();
+        ints.add_series0("x", Int64Array::from_vec(vec![1, 2]).boxed())
+            .unwrap();
+        ints.add_series0("y", Int64Array::from_vec(vec![3, 4]).boxed())
+            .unwrap();
+        let melted = ints.melt(&[], &["x", "y"], "var", "val").unwrap();
+        assert_eq!(melted.names(), ["var", "val"]);
+        assert_eq!(
+            melted.get_int64("val").unwrap(),
+            &Int64Array::from_vec(vec![1, 3, 2, 4])
+        );
+        assert!(matches!(
+            wide.melt(&["id"], &["id", "a"], "var", "val"),
+            Err(Error::TypeMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let mut df = DataFrame::new0();
+        for (name, values) in [("x", [1.0, 2.0]), ("y", [3.0, 4.0]), ("z", [5.0, 6.0])] {
+            df.add_series0(name, Float64Array::from_slice(values).boxed())
+                .unwrap();
+        }
+        let transposed = df.transpose().unwrap();
+        assert_eq!(transposed.names(), ["col_0", "col_1"]);
+        assert_eq!(transposed.rows(), Some(3));
+        assert_eq!(
+            transposed.get_float64("col_0").unwrap(),
+            &Float64Array::from_slice([1.0, 3.0, 5.0])
+        );
+        assert_eq!(
+            transposed.get_float64("col_1").unwrap(),
+            &Float64Array::from_slice([2.0, 4.0, 6.0])
+        );
+        let back = transposed.transpose().unwrap();
+        assert_eq!(back.names(), ["col_0", "col_1", "col_2"]);
+        assert!(back
+            .data()
+            .iter()
+            .zip(df.data())
+            .all(|(a, b)| a.as_ref() == b.as_ref()));
+        assert!(DataFrame::new0().transpose().unwrap().is_empty());
+        df.add_series0("i", Int64Array::from_vec(vec![1, 2]).boxed())
+            .unwrap();
+        assert!(matches!(df.transpose(), Err(Error::TypeMismatch)));
+    }
+}
@@ -0,0 +1,577 @@
+#[cfg(feature = "arrow2_ih")]
+extern crate arrow2_ih as arrow2;
+
+use crate::df::DataFrame;
+use crate::Error;
+use arrow2::array::{Array, Float64Array, Utf8Array};
+use arrow2::compute::aggregate;
+use arrow2::compute::cast::{cast, CastOptions};
+use arrow2::datatypes::{DataType, Field};
+
+const DESCRIBE_STATS: [&str; 9] = [
+    "count",
+    "null_count",
+    "mean",
+    "std",
+    "min",
+    "25%",
+    "50%",
+    "75%",
+    "max",
+];
+
+#[cfg(feature = "rayon")]
+const MIN_PARALLEL_CHUNK: usize = 65_536;
+
+impl DataFrame {
+    /// Minimum of a Float64 column, None if there are no non-null values
+    #[inline]
+    pub fn column_min_float(&self, name: &str) -> Result<Option<f64>, Error> {
+        Ok(aggregate::min_primitive(self.get_float64(name)?))
+    }
+    /// Maximum of a Float64 column, None if there are no non-null values
+    #[inline]
+    pub fn column_max_float(&self, name: &str) -> Result<Option<f64>, Error> {
+        Ok(aggregate::max_primitive(self.get_float64(name)?))
+    }
+    /// Minimum of an Int64 column, None if there are no non-null values
+    #[inline]
+    pub fn column_min_int(&self, name: &str) -> Result<Option<i64>, Error> {
+        Ok(aggregate::min_primitive(self.get_int64(name)?))
+    }
+    /// Maximum of an Int64 column, None if there are no non-null values
+    #[inline]
+    pub fn column_max_int(&self, name: &str) -> Result<Option<i64>, Error> {
+        Ok(aggregate::max_primitive(self.get_int64(name)?))
+    }
+    /// Minimum and maximum of a Float64 column
+    pub fn column_range_float(&self, name: &str) -> Result<(Option<f64>, Option<f64>), Error> {
+        let values = self.get_float64(name)?;
+        Ok((
+            aggregate::min_primitive(values),
+            aggregate::max_primitive(values),
+        ))
+    }
+    /// Sum of a Float64 column, None if there are no non-null values
+    pub fn column_sum_float(&self, name: &str) -> Result<Option<f64>, Error> {
+        let values = self.get_float64(name)?;
+        if values.len() == values.null_count() {
+            Ok(None)
+        } else {
+            Ok(aggregate::sum_primitive(values))
+        }
+    }
+    /// Sum of an Int64 column, None if there are no non-null values
+    ///
+    /// Returns [`Error::Overflow`] if the sum does not fit into i64
+    pub fn column_sum_int(&self, name: &str) -> Result<Option<i64>, Error> {
+        let values = self.get_int64(name)?;
+        if values.len() == values.null_count() {
+            return Ok(None);
+        }
+        values
+            .iter()
+            .flatten()
+            .try_fold(0i64, |acc, v| acc.checked_add(*v))
+            .map(Some)
+            .ok_or(Error::Overflow)
+    }
+    /// Mean of a Float64 column, None if there are no non-null values
+    #[allow(clippy::cast_precision_loss)]
+    pub fn column_mean(&self, name: &str) -> Result<Option<f64>, Error> {
+        let values = self.get_float64(name)?;
+        let count = values.len() - values.null_count();
+        if count == 0 {
+            Ok(None)
+        } else {
+            Ok(aggregate::sum_primitive(values).map(|sum| sum / count as f64))
+        }
+    }
+    /// Mean of a Float64 column, calculated in parallel
+    #[cfg(feature = "rayon")]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn column_mean_parallel(&self, name: &str) -> Result<Option<f64>, Error> {
+        use rayon::prelude::*;
+        let values = self.get_float64(name)?;
+        let len = values.len();
+        let chunk_size = (len / rayon::current_num_threads()).max(MIN_PARALLEL_CHUNK);
+        let (sum, count) = (0..(len + chunk_size - 1) / chunk_size)
+            .into_par_iter()
+            .map(|i| {
+                let offset = i * chunk_size;
+                let chunk =
+                    Float64Array::sliced(values.clone(), offset, chunk_size.min(len - offset));
+                (
+                    aggregate::sum_primitive(&chunk).unwrap_or_default(),
+                    chunk.len() - chunk.null_count(),
+                )
+            })
+            .reduce(|| (0.0, 0), |a, b| (a.0 + b.0, a.1 + b.1));
+        if count == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(sum / count as f64))
+        }
+    }
+    /// Median of a Float64 column, None if there are no non-null values
+    ///
+    /// For an even number of values the average of the two central ones is returned
+    #[inline]
+    pub fn column_median(&self, name: &str) -> Result<Option<f64>, Error> {
+        self.column_percentile(name, 0.5)
+    }
+    /// Percentile (q = 0.0..=1.0) of a Float64 column, linear interpolation is used between
+    /// adjacent values. None if there are no non-null values
+    pub fn column_percentile(&self, name: &str, q: f64) -> Result<Option<f64>, Error> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(Error::InvalidArgument(format!(
+                "percentile must be in [0.0, 1.0], got {}",
+                q
+            )));
+        }
+        let mut values: Vec<f64> = self.get_float64(name)?.iter().flatten().copied().collect();
+        if values.is_empty() {
+            return Ok(None);
+        }
+        values.sort_by(f64::total_cmp);
+        Ok(Some(percentile_sorted(&values, q)))
+    }
+    /// Variance of a Float64 column, ddof = 0 for population, 1 for sample
+    ///
+    /// None if there are less than ddof + 1 non-null values
+    #[allow(clippy::cast_precision_loss)]
+    pub fn column_var(&self, name: &str, ddof: u8) -> Result<Option<f64>, Error> {
+        let (count, _, m2) = welford(self.get_float64(name)?);
+        let ddof = usize::from(ddof);
+        if count > ddof {
+            Ok(Some(m2 / (count - ddof) as f64))
+        } else {
+            Ok(None)
+        }
+    }
+    /// Standard deviation of a Float64 column, ddof = 0 for population, 1 for sample
+    ///
+    /// None if there are less than ddof + 1 non-null values
+    #[inline]
+    pub fn column_std(&self, name: &str, ddof: u8) -> Result<Option<f64>, Error> {
+        Ok(self.column_var(name, ddof)?.map(f64::sqrt))
+    }
+    /// Pearson correlation coefficient of two Float64 columns, rows with nulls are skipped
+    ///
+    /// None if there are no such rows or any of the columns has zero variance
+    pub fn correlation(&self, a: &str, b: &str) -> Result<Option<f64>, Error> {
+        let pairs = paired(self.get_float64(a)?, self.get_float64(b)?);
+        if pairs.is_empty() {
+            return Ok(None);
+        }
+        let (mean_x, mean_y) = paired_means(&pairs);
+        let (mut sxy, mut sxx, mut syy) = (0.0, 0.0, 0.0);
+        for (x, y) in &pairs {
+            let dx = x - mean_x;
+            let dy = y - mean_y;
+            sxy += dx * dy;
+            sxx += dx * dx;
+            syy += dy * dy;
+        }
+        if sxx == 0.0 || syy == 0.0 {
+            Ok(None)
+        } else {
+            Ok(Some(sxy / (sxx * syy).sqrt()))
+        }
+    }
+    /// Covariance of two Float64 columns, ddof = 0 for population, 1 for sample. Rows with
+    /// nulls are skipped
+    ///
+    /// None if there are less than ddof + 1 such rows
+    #[allow(clippy::cast_precision_loss)]
+    pub fn covariance(&self, a: &str, b: &str, ddof: u8) -> Result<Option<f64>, Error> {
+        let pairs = paired(self.get_float64(a)?, self.get_float64(b)?);
+        let ddof = usize::from(ddof);
+        if pairs.len() <= ddof {
+            return Ok(None);
+        }
+        let (mean_x, mean_y) = paired_means(&pairs);
+        let sxy: f64 = pairs.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+        Ok(Some(sxy / (pairs.len() - ddof) as f64))
+    }
+    /// Summary statistics of numeric and string columns
+    ///
+    /// Returns a data frame with Utf8 "stat" column (count, null_count, mean, std, min, 25%,
+    /// 50%, 75%, max) and a Float64 column per each numeric column of the source. If the source
+    /// has a column named "stat", underscores are appended to the stat column name. String columns
+    /// have only count and null_count set, other stats are NaN. Columns of other types are
+    /// skipped, as well as numeric ones which can not be cast to Float64. The standard deviation
+    /// is calculated for a sample (ddof = 1)
+    ///
+    /// # Panics
+    ///
+    /// should not panic
+    #[allow(clippy::cast_precision_loss)]
+    pub fn describe(&self) -> Self {
+        let mut stat_name = "stat".to_owned();
+        while self.get_column_index(&stat_name).is_some() {
+            stat_name.push('_');
+        }
+        let mut fields = vec![Field::new(&stat_name, DataType::Utf8, false)];
+        let mut data = vec![Utf8Array::<i32>::from_slice(DESCRIBE_STATS).boxed()];
+        for (field, serie) in self.fields().iter().zip(self.data()) {
+            let stats = match field.data_type() {
+                DataType::Float64
+                | DataType::Float32
+                | DataType::Int64
+                | DataType::Int32
+                | DataType::Int16
+                | DataType::Int8
+                | DataType::UInt64
+                | DataType::UInt32
+                | DataType::UInt16
+                | DataType::UInt8 => {
+                    let values =
+                        cast(serie.as_ref(), &DataType::Float64, CastOptions::default()).ok();
+                    if let Some(values) = values
+                        .as_ref()
+                        .and_then(|v| v.as_any().downcast_ref::<Float64Array>())
+                    {
+                        describe_numeric(values)
+                    } else {
+                        continue;
+                    }
+                }
+                DataType::Utf8 | DataType::LargeUtf8 => {
+                    let mut stats = [f64::NAN; 9];
+                    stats[0] = (serie.len() - serie.null_count()) as f64;
+                    stats[1] = serie.null_count() as f64;
+                    stats
+                }
+                _ => continue,
+            };
+            fields.push(Field::new(&field.name, DataType::Float64, false));
+            data.push(Float64Array::from_slice(stats).boxed());
+        }
+        DataFrame::from_parts(fields, data, None).unwrap()
+    }
+}
+
+fn paired(a: &Float64Array, b: &Float64Array) -> Vec<(f64, f64)> {
+    a.iter()
+        .zip(b.iter())
+        .filter_map(|(x, y)| Some((*x?, *y?)))
+        .collect()
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn paired_means(pairs: &[(f64, f64)]) -> (f64, f64) {
+    let n = pairs.len() as f64;
+    let (sum_x, sum_y) = pairs
+        .iter()
+        .fold((0.0, 0.0), |acc, (x, y)| (acc.0 + x, acc.1 + y));
+    (sum_x / n, sum_y / n)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn describe_numeric(values: &Float64Array) -> [f64; 9] {
+    let (count, mean, m2) = welford(values);
+    let mut stats = [f64::NAN; 9];
+    stats[0] = count as f64;
+    stats[1] = values.null_count() as f64;
+    if count > 0 {
+        let mut sorted: Vec<f64> = values.iter().flatten().copied().collect();
+        sorted.sort_by(f64::total_cmp);
+        stats[2] = mean;
+        if count > 1 {
+            stats[3] = (m2 / (count - 1) as f64).sqrt();
+        }
+        stats[4] = sorted[0];
+        stats[5] = percentile_sorted(&sorted, 0.25);
+        stats[6] = percentile_sorted(&sorted, 0.5);
+        stats[7] = percentile_sorted(&sorted, 0.75);
+        stats[8] = sorted[count - 1];
+    }
+    stats
+}
+
+/// Welford's online algorithm, returns non-null count, mean and sum of squared differences
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn welford(values: &Float64Array) -> (usize, f64, f64) {
+    let mut count = 0;
+    let mut mean = 0.0;
+    let mut m2 = 0.0;
+    for v in values.iter().flatten() {
+        count += 1;
+        let delta = v - mean;
+        mean += delta / count as f64;
+        m2 += delta * (v - mean);
+    }
+    (count, mean, m2)
+}
+
+/// Percentile of sorted non-empty values with linear interpolation
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+pub(crate) fn percentile_sorted(values: &[f64], q: f64) -> f64 {
+    let pos = q * (values.len() - 1) as f64;
+    let lower = pos.floor() as usize;
+    let upper = pos.ceil() as usize;
+    if lower == upper {
+        values[lower]
+    } else {
+        values[lower] + (values[upper] - values[lower]) * (pos - lower as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow2::array::Int64Array;
+
+    fn float_df(values: Vec<Option<f64>>) -> DataFrame {
+        let mut df = DataFrame::new0();
+        df.add_series0("x", Float64Array::from(values).boxed())
+            .unwrap();
+        df
+    }
+
+    #[test]
+    fn test_column_var_std() {
+        let values = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let df = float_df(values.iter().map(|v| Some(*v)).chain([None]).collect());
+        assert!((df.column_var("x", 0).unwrap().unwrap() - 4.0).abs() < 1e-10);
+        assert!((df.column_std("x", 0).unwrap().unwrap() - 2.0).abs() < 1e-10);
+        assert!((df.column_var("x", 1).unwrap().unwrap() - 32.0 / 7.0).abs() < 1e-10);
+        assert!((df.column_std("x", 1).unwrap().unwrap() - (32.0_f64 / 7.0).sqrt()).abs() < 1e-10);
+        let shifted = float_df(values.iter().map(|v| Some(v + 1e9)).collect());
+        assert!((shifted.column_var("x", 0).unwrap().unwrap() - 4.0).abs() < 1e-6);
+    }
+    #[test]
+    fn test_column_var_std_few_values() {
+        let df = float_df(vec![Some(3.0), None]);
+        assert_eq!(df.column_var("x", 0).unwrap(), Some(0.0));
+        assert_eq!(df.column_var("x", 1).unwrap(), None);
+        assert_eq!(df.column_std("x", 1).unwrap(), None);
+        let df = float_df(vec![None, None]);
+        assert_eq!(df.column_var("x", 0).unwrap(), None);
+        assert!(matches!(df.column_std("y", 0), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn test_column_sum() {
+        let mut df = float_df(vec![Some(1.5), None, Some(2.5)]);
+        df.add_series0("i", Int64Array::from(vec![Some(1), None, Some(-4)]).boxed())
+            .unwrap();
+        assert_eq!(df.column_sum_float("x").unwrap(), Some(4.0));
+        assert_eq!(df.column_sum_int("i").unwrap(), Some(-3));
+        assert!(matches!(df.column_sum_float("i"), Err(Error::TypeMismatch)));
+        assert!(matches!(df.column_sum_int("x"), Err(Error::TypeMismatch)));
+        let mut df = float_df(vec![None, None]);
+        df.add_series0("i", Int64Array::from(vec![None, None]).boxed())
+            .unwrap();
+        assert_eq!(df.column_sum_float("x").unwrap(), None);
+        assert_eq!(df.column_sum_int("i").unwrap(), None);
+        let mut df = DataFrame::new0();
+        df.add_series0("i", Int64Array::from_vec(vec![i64::MAX, 1]).boxed())
+            .unwrap();
+        assert!(matches!(df.column_sum_int("i"), Err(Error::Overflow)));
+    }
+
+    fn pair_df(x: Vec<Option<f64>>, y: Vec<Option<f64>>) -> DataFrame {
+        let mut df = float_df(x);
+        df.add_series0("y", Float64Array::from(y).boxed()).unwrap();
+        df
+    }
+    #[test]
+    fn test_correlation() {
+        let x = vec![Some(1.0), Some(2.0), Some(4.0), Some(8.0)];
+        let df = pair_df(x.clone(), x.clone());
+        assert!((df.correlation("x", "y").unwrap().unwrap() - 1.0).abs() < 1e-12);
+        let df = pair_df(x.clone(), x.iter().map(|v| v.map(|v| -v)).collect());
+        assert!((df.correlation("x", "y").unwrap().unwrap() + 1.0).abs() < 1e-12);
+        let x: Vec<Option<f64>> = [-2.0, -1.0, 0.0, 1.0, 2.0]
+            .iter()
+            .map(|v| Some(*v))
+            .collect();
+        let y = x.iter().map(|v| v.map(|v| v * v)).collect();
+        let df = pair_df(x, y);
+        assert!(df.correlation("x", "y").unwrap().unwrap().abs() < 1e-12);
+        let df = pair_df(vec![Some(1.0), Some(2.0)], vec![Some(3.0), Some(3.0)]);
+        assert_eq!(df.correlation("x", "y").unwrap(), None);
+        let df = pair_df(vec![None, None], vec![Some(1.0), Some(2.0)]);
+        assert_eq!(df.correlation("x", "y").unwrap(), None);
+        let mut df = pair_df(vec![Some(1.0)], vec![Some(2.0)]);
+        df.add_series0("i", Int64Array::from_vec(vec![1]).boxed())
+            .unwrap();
+        assert!(matches!(df.correlation("x", "z"), Err(Error::NotFound(_))));
+        assert!(matches!(df.correlation("i", "y"), Err(Error::TypeMismatch)));
+    }
+
+    #[test]
+    fn test_covariance() {
+        let df = pair_df(
+            vec![Some(2.0), Some(4.0), None, Some(4.0), Some(5.0), Some(9.0)],
+            vec![
+                Some(1.0),
+                Some(3.0),
+                Some(7.0),
+                Some(2.0),
+                Some(8.0),
+                Some(6.0),
+            ],
+        );
+        for ddof in [0, 1] {
+            let cov = df.covariance("x", "x", ddof).unwrap().unwrap();
+            assert!((cov - df.column_var("x", ddof).unwrap().unwrap()).abs() < 1e-12);
+            let xy = df.covariance("x", "y", ddof).unwrap().unwrap();
+            let yx = df.covariance("y", "x", ddof).unwrap().unwrap();
+            assert!((xy - yx).abs() < 1e-12);
+        }
+        // pairs (2, 1), (4, 3), (4, 2), (5, 8), (9, 6): means 4.8 and 4.0, sum of products 20.0
+        assert!((df.covariance("x", "y", 0).unwrap().unwrap() - 4.0).abs() < 1e-12);
+        assert!((df.covariance("x", "y", 1).unwrap().unwrap() - 5.0).abs() < 1e-12);
+        let df = pair_df(vec![Some(1.0), None], vec![Some(2.0), Some(3.0)]);
+        assert_eq!(df.covariance("x", "y", 0).unwrap(), Some(0.0));
+        assert_eq!(df.covariance("x", "y", 1).unwrap(), None);
+        assert!(matches!(
+            df.covariance("x", "z", 0),
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_describe() {
+        let mut df = float_df(vec![Some(1.0), Some(2.0), Some(3.0), Some(4.0), None]);
+        df.add_series0("i", Int64Array::from_vec(vec![5; 5]).boxed())
+            .unwrap();
+        df.add_series0(
+            "s",
+            Utf8Array::<i32>::from(vec![Some("a"), None, Some("b"), Some("c"), None]).boxed(),
+        )
+        .unwrap();
+        df.add_series0(
+            "b",
+            arrow2::array::BooleanArray::from_slice([true; 5]).boxed(),
+        )
+        .unwrap();
+        let stats = df.describe();
+        assert_eq!(stats.names(), ["stat", "x", "i", "s"]);
+        assert_eq!(
+            stats.get_utf8_iter("stat").unwrap().collect::<Vec<_>>(),
+            DESCRIBE_STATS.iter().map(|s| Some(*s)).collect::<Vec<_>>()
+        );
+        let values = |name: &str| stats.get_float64(name).unwrap().values().to_vec();
+        let x = values("x");
+        let expected = [
+            4.0,
+            1.0,
+            2.5,
+            (5.0_f64 / 3.0).sqrt(),
+            1.0,
+            1.75,
+            2.5,
+            3.25,
+            4.0,
+        ];
+        assert!(x.iter().zip(expected).all(|(a, b)| (a - b).abs() < 1e-12));
+        assert_eq!(values("i"), [5.0, 0.0, 5.0, 0.0, 5.0, 5.0, 5.0, 5.0, 5.0]);
+        let s = values("s");
+        assert_eq!(s[..2], [3.0, 2.0]);
+        assert!(s[2..].iter().all(|v| v.is_nan()));
+        let mut df = float_df(vec![Some(1.0)]);
+        df.rename("x", "stat").unwrap();
+        let stats = df.describe();
+        assert_eq!(stats.names(), ["stat_", "stat"]);
+        let stats = float_df(vec![None]).describe();
+        let x = stats.get_float64("x").unwrap().values();
+        assert_eq!(x[..2], [0.0, 1.0]);
+        assert!(x[2..].iter().all(|v| v.is_nan()));
+        assert_eq!(DataFrame::new0().describe().names(), ["stat"]);
+    }
+
+    #[test]
+    fn test_column_min_max() {
+        let mut df = float_df(vec![Some(1.5), None, Some(-2.5), Some(0.0)]);
+        df.add_series0(
+            "i",
+            Int64Array::from(vec![Some(3), Some(-7), None, Some(10)]).boxed(),
+        )
+        .unwrap();
+        assert_eq!(df.column_min_float("x").unwrap(), Some(-2.5));
+        assert_eq!(df.column_max_float("x").unwrap(), Some(1.5));
+        assert_eq!(df.column_min_int("i").unwrap(), Some(-7));
+        assert_eq!(df.column_max_int("i").unwrap(), Some(10));
+        assert_eq!(df.column_range_float("x").unwrap(), (Some(-2.5), Some(1.5)));
+        assert!(matches!(df.column_min_float("i"), Err(Error::TypeMismatch)));
+        assert!(matches!(df.column_max_int("x"), Err(Error::TypeMismatch)));
+        assert!(matches!(df.column_min_int("y"), Err(Error::NotFound(_))));
+        let mut df = float_df(vec![None, None]);
+        df.add_series0("i", Int64Array::from(vec![None, None]).boxed())
+            .unwrap();
+        assert_eq!(df.column_min_float("x").unwrap(), None);
+        assert_eq!(df.column_max_float("x").unwrap(), None);
+        assert_eq!(df.column_min_int("i").unwrap(), None);
+        assert_eq!(df.column_max_int("i").unwrap(), None);
+    }
+
+    #[test]
+    fn test_column_mean() {
+        let mut df = float_df(vec![Some(1.0), None, Some(2.0), Some(6.0)]);
+        df.add_series0("i", Int64Array::from_vec(vec![1, 2, 3, 4]).boxed())
+            .unwrap();
+        assert_eq!(df.column_mean("x").unwrap(), Some(3.0));
+        assert!(matches!(df.column_mean("i"), Err(Error::TypeMismatch)));
+        assert!(matches!(df.column_mean("y"), Err(Error::NotFound(_))));
+        assert_eq!(float_df(vec![None, None]).column_mean("x").unwrap(), None);
+        assert_eq!(float_df(vec![]).column_mean("x").unwrap(), None);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_column_mean_parallel() {
+        // more than one chunk, the last one is shorter
+        let len = MIN_PARALLEL_CHUNK * 2 + 123;
+        #[allow(clippy::cast_precision_loss)]
+        let values: Vec<Option<f64>> = (0..len)
+            .map(|i| if i % 7 == 0 { None } else { Some(i as f64) })
+            .collect();
+        let df = float_df(values);
+        let expected = df.column_mean("x").unwrap().unwrap();
+        let mean = df.column_mean_parallel("x").unwrap().unwrap();
+        assert!((mean - expected).abs() < 1e-6);
+        let df = float_df(vec![Some(1.0), None, Some(2.0)]);
+        assert_eq!(df.column_mean_parallel("x").unwrap(), Some(1.5));
+        assert_eq!(
+            float_df(vec![None; MIN_PARALLEL_CHUNK + 1])
+                .column_mean_parallel("x")
+                .unwrap(),
+            None
+        );
+        let mut df = DataFrame::new0();
+        df.add_series0("i", Int64Array::from_vec(vec![1]).boxed())
+            .unwrap();
+        assert!(matches!(
+            df.column_mean_parallel("i"),
+            Err(Error::TypeMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_column_median_percentile() {
+        let df = float_df(vec![Some(4.0), None, Some(1.0), Some(3.0), Some(2.0)]);
+        assert_eq!(df.column_median("x").unwrap(), Some(2.5));
+        assert_eq!(df.column_percentile("x", 0.0).unwrap(), Some(1.0));
+        assert_eq!(df.column_percentile("x", 0.25).unwrap(), Some(1.75));
+        assert_eq!(df.column_percentile("x", 1.0).unwrap(), Some(4.0));
+        let df = float_df(vec![Some(5.0), Some(1.0), None, Some(3.0)]);
+        assert_eq!(df.column_median("x").unwrap(), Some(3.0));
+        assert_eq!(df.column_percentile("x", 0.75).unwrap(), Some(4.0));
+        for q in [-0.1, 1.1, f64::NAN] {
+            assert!(matches!(
+                df.column_percentile("x", q),
+                Err(Error::InvalidArgument(_))
+            ));
+        }
+        assert_eq!(float_df(vec![None]).column_median("x").unwrap(), None);
+        let mut df = DataFrame::new0();
+        df.add_series0("i", Int64Array::from_vec(vec![1, 2]).boxed())
+            .unwrap();
+        assert!(matches!(df.column_median("i"), Err(Error::TypeMismatch)));
+    }
+}
@@ -0,0 +1,400 @@
+#[cfg(feature = "arrow2_ih")]
+extern crate arrow2_ih as arrow2;
+
+use crate::df::DataFrame;
+use crate::Error;
+use arrow2::array::{BooleanArray, Utf8Array};
+use arrow2::datatypes::DataType;
+
+impl DataFrame {
+    /// Convert values of a Utf8/LargeUtf8 column to lowercase
+    #[inline]
+    pub fn string_to_lowercase(&mut self, name: &str) -> Result<(), Error> {
+        self.apply_str(name, |v| v.map(str::to_lowercase))
+    }
+    /// Convert values of a Utf8/LargeUtf8 column to uppercase
+    #[inline]
+    pub fn string_to_uppercase(&mut self, name: &str) -> Result<(), Error> {
+        self.apply_str(name, |v| v.map(str::to_uppercase))
+    }
+    /// Trim leading and trailing ASCII whitespace of a Utf8/LargeUtf8 column values
+    #[inline]
+    pub fn string_trim(&mut self, name: &str) -> Result<(), Error> {
+        self.apply_str(name, |v| {
+            v.map(|s| s.trim_matches(|c: char| c.is_ascii_whitespace()).to_owned())
+        })
+    }
+    /// Replace all occurrences of a substring in a Utf8/LargeUtf8 column values
+    ///
+    /// No-op if the substring is empty
+    pub fn string_replace(&mut self, name: &str, from: &str, to: &str) -> Result<(), Error> {
+        if from.is_empty() {
+            self.get_utf8_iter(name)?;
+            return Ok(());
+        }
+        self.apply_str(name, |v| v.map(|s| s.replace(from, to)))
+    }
+    /// Replace the first occurrence of a substring in a Utf8/LargeUtf8 column values
+    ///
+    /// No-op if the substring is empty
+    pub fn string_replace_first(&mut self, name: &str, from: &str, to: &str) -> Result<(), Error> {
+        if from.is_empty() {
+            self.get_utf8_iter(name)?;
+            return Ok(());
+        }
+        self.apply_str(name, |v| v.map(|s| s.replacen(from, to, 1)))
+    }
+    /// Split a Utf8/LargeUtf8 column on a delimiter and replace it with max_parts columns, named
+    /// {name}_0, {name}_1 etc.
+    ///
+    /// Values with fewer parts produce nulls in the trailing columns, excess parts are kept
+    /// joined in the last column
+    pub fn string_split_column(
+        &mut self,
+        name: &str,
+        delimiter: char,
+        max_parts: usize,
+    ) -> Result<(), Error> {
+        if max_parts == 0 {
+            return Err(Error::InvalidArgument(
+                "max_parts must be positive".to_owned(),
+            ));
+        }
+        let index = self.column_index(name)?;
+        let large = self.fields()[index].data_type() == &DataType::LargeUtf8;
+        let names: Vec<String> = (0..max_parts).map(|i| format!("{}_{}", name, i)).collect();
+        for n in &names {
+            if self.get_column_index(n).is_some() {
+                return Err(Error::AlreadyExists(n.clone()));
+            }
+        }
+        let rows = self.rows().unwrap_or_default();
+        let mut parts: Vec<Vec<Option<String>>> = vec![Vec::with_capacity(rows); max_parts];
+        for value in self.get_utf8_iter(name)? {
+            let mut split = value.map(|s| s.splitn(max_parts, delimiter));
+            for part in &mut parts {
+                part.push(split.as_mut().and_then(Iterator::next).map(str::to_owned));
+            }
+        }
+        self.drop_column(name)?;
+        for (i, (n, part)) in names.iter().zip(parts).enumerate() {
+            let series = if large {
+                Utf8Array::<i64>::from(part).boxed()
+            } else {
+                Utf8Array::<i32>::from(part).boxed()
+            };
+            self.insert_series0(n, series, index + i)?;
+        }
+        Ok(())
+    }
+    /// Concatenate two Utf8/LargeUtf8 columns with a separator into a new Utf8 column
+    ///
+    /// If strict is true, the result is null if any of the values is null, otherwise the
+    /// non-null value is used alone
+    pub fn concat_string_columns(
+        &mut self,
+        a: &str,
+        b: &str,
+        separator: &str,
+        dest: &str,
+        strict: bool,
+    ) -> Result<(), Error> {
+        let result: Utf8Array<i32> = self
+            .get_utf8_iter(a)?
+            .zip(self.get_utf8_iter(b)?)
+            .map(|(x, y)| match (x, y) {
+                (Some(x), Some(y)) => Some(format!("{}{}{}", x, separator, y)),
+                (Some(v), None) | (None, Some(v)) if !strict => Some(v.to_owned()),
+                _ => None,
+            })
+            .collect();
+        self.add_series0(dest, result.boxed())
+    }
+    /// Mask of Utf8/LargeUtf8 column values containing the pattern, nulls remain null
+    #[inline]
+    pub fn string_contains(&self, name: &str, pattern: &str) -> Result<BooleanArray, Error> {
+        self.string_mask(name, |s| s.contains(pattern))
+    }
+    /// Mask of Utf8/LargeUtf8 column values starting with the pattern, nulls remain null
+    #[inline]
+    pub fn string_startswith(&self, name: &str, pattern: &str) -> Result<BooleanArray, Error> {
+        self.string_mask(name, |s| s.starts_with(pattern))
+    }
+    /// Mask of Utf8/LargeUtf8 column values ending with the pattern, nulls remain null
+    #[inline]
+    pub fn string_endswith(&self, name: &str, pattern: &str) -> Result<BooleanArray, Error> {
+        self.string_mask(name, |s| s.ends_with(pattern))
+    }
+    /// Create a new data frame with rows where a Utf8/LargeUtf8 column value contains the
+    /// pattern
+    ///
+    /// Rows with null values are excluded
+    #[inline]
+    pub fn filter_string_contains(&self, name: &str, pattern: &str) -> Result<Self, Error> {
+        self.filter(&self.string_contains(name, pattern)?)
+    }
+    fn string_mask<F>(&self, name: &str, func: F) -> Result<BooleanArray, Error>
+    where
+        F: Fn(&str) -> bool,
+    {
+        Ok(self.get_utf8_iter(name)?.map(|v| v.map(&func)).collect())
+    }
+    /// Extract a regex capture group of a Utf8/LargeUtf8 column values into a new Utf8 column
+    ///
+    /// Group 0 is the whole match. Values which do not match produce nulls
+    #[cfg(feature = "regex")]
+    pub fn string_extract_regex(
+        &mut self,
+        name: &str,
+        pattern: &str,
+        group: usize,
+        dest: &str,
+    ) -> Result<(), Error> {
+        let re = regex::Regex::new(pattern).map_err(|e| Error::InvalidArgument(e.to_string()))?;
+        if group >= re.captures_len() {
+            return Err(Error::InvalidArgument(format!(
+                "capture group {} not found, the pattern has {} group(s)",
+                group,
+                re.captures_len() - 1
+            )));
+        }
+        let result: Utf8Array<i32> = self
+            .get_utf8_iter(name)?
+            .map(|v| {
+                v.and_then(|s| re.captures(s))
+                    .and_then(|c| c.get(group))
+                    .map(|m| m.as_str().to_owned())
+            })
+            .collect();
+        self.add_series0(dest, result.boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn str_df(values: Vec<Option<&str>>) -> DataFrame {
+        let mut df = DataFrame::new0();
+        df.add_series0("s", Utf8Array::<i32>::from(values).boxed())
+            .unwrap();
+        df
+    }
+
+    fn strings<'a>(df: &'a DataFrame, name: &str) -> Vec<Option<&'a str>> {
+        df.get_utf8_iter(name).unwrap().collect()
+    }
+
+    #[test]
+    fn test_string_case_trim() {
+        let mut df = str_df(vec![Some("Straße ÄÖÜ"), None, Some("ΣΊΣΥΦΟΣ"), Some("abc")]);
+        df.string_to_uppercase("s").unwrap();
+        assert_eq!(
+            strings(&df, "s"),
+            vec![Some("STRASSE ÄÖÜ"), None, Some("ΣΊΣΥΦΟΣ"), Some("ABC")]
+        );
+        df.string_to_lowercase("s").unwrap();
+        assert_eq!(
+            strings(&df, "s"),
+            vec![Some("strasse äöü"), None, Some("σίσυφος"), Some("abc")]
+        );
+        let mut df = str_df(vec![Some(" \t a b \n"), Some("\u{a0}x\u{a0}"), None]);
+        df.string_trim("s").unwrap();
+        assert_eq!(
+            strings(&df, "s"),
+            vec![Some("a b"), Some("\u{a0}x\u{a0}"), None]
+        );
+        let mut df = DataFrame::new0();
+        df.add_series0("s", Utf8Array::<i64>::from_slice([" Ab "]).boxed())
+            .unwrap();
+        df.string_trim("s").unwrap();
+        df.string_to_lowercase("s").unwrap();
+        assert_eq!(df.fields()[0].data_type, DataType::LargeUtf8);
+        assert_eq!(strings(&df, "s"), vec![Some("ab")]);
+        df.add_series0("b", BooleanArray::from_slice([true]).boxed())
+            .unwrap();
+        assert!(matches!(
+            df.string_to_uppercase("b"),
+            Err(Error::TypeMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_string_masks() {
+        let df = str_df(vec![Some("привет мир"), None, Some("hello"), Some("")]);
+        assert_eq!(
+            df.string_contains("s", "")
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(true), None, Some(true), Some(true)]
+        );
+        assert_eq!(
+            df.string_contains("s", "вет")
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(true), None, Some(false), Some(false)]
+        );
+        assert_eq!(
+            df.string_startswith("s", "при")
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(true), None, Some(false), Some(false)]
+        );
+        assert_eq!(
+            df.string_endswith("s", "lo")
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(false), None, Some(true), Some(false)]
+        );
+        assert_eq!(
+            df.string_endswith("s", "")
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(true), None, Some(true), Some(true)]
+        );
+        let filtered = df.filter_string_contains("s", "мир").unwrap();
+        assert_eq!(strings(&filtered, "s"), vec![Some("привет мир")]);
+        assert_eq!(df.filter_string_contains("s", "").unwrap().rows(), Some(3));
+        assert!(matches!(
+            df.string_contains("x", ""),
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_string_replace() {
+        let values = vec![Some("aaaa"), None, Some("banana"), Some("")];
+        let mut df = str_df(values.clone());
+        df.string_replace("s", "aa", "b").unwrap();
+        assert_eq!(
+            strings(&df, "s"),
+            vec![Some("bb"), None, Some("banana"), Some("")]
+        );
+        let mut df = str_df(values.clone());
+        df.string_replace("s", "ana", "_").unwrap();
+        assert_eq!(
+            strings(&df, "s"),
+            vec![Some("aaaa"), None, Some("b_na"), Some("")]
+        );
+        let mut df = str_df(values.clone());
+        df.string_replace_first("s", "a", "o").unwrap();
+        assert_eq!(
+            strings(&df, "s"),
+            vec![Some("oaaa"), None, Some("bonana"), Some("")]
+        );
+        let mut df = str_df(values.clone());
+        df.string_replace("s", "", "x").unwrap();
+        df.string_replace_first("s", "", "x").unwrap();
+        assert_eq!(strings(&df, "s"), values);
+        df.add_series0("b", BooleanArray::from_slice([true; 4]).boxed())
+            .unwrap();
+        assert!(matches!(
+            df.string_replace("b", "", "x"),
+            Err(Error::TypeMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_string_split_column() {
+        let mut df = str_df(vec![Some("a,b,c,d"), Some("x"), None, Some("p,q")]);
+        df.add_series0("n", BooleanArray::from_slice([true; 4]).boxed())
+            .unwrap();
+        let mut split = df.clone();
+        split.string_split_column("s", ',', 3).unwrap();
+        assert_eq!(split.names(), vec!["s_0", "s_1", "s_2", "n"]);
+        assert_eq!(
+            strings(&split, "s_0"),
+            vec![Some("a"), Some("x"), None, Some("p")]
+        );
+        assert_eq!(
+            strings(&split, "s_1"),
+            vec![Some("b"), None, None, Some("q")]
+        );
+        assert_eq!(strings(&split, "s_2"), vec![Some("c,d"), None, None, None]);
+        let mut split = df.clone();
+        split.string_split_column("s", ',', 1).unwrap();
+        assert_eq!(split.names(), vec!["s_0", "n"]);
+        assert_eq!(
+            strings(&split, "s_0"),
+            vec![Some("a,b,c,d"), Some("x"), None, Some("p,q")]
+        );
+        let mut split = df.clone();
+        split.string_split_column("s", ';', 2).unwrap();
+        assert_eq!(
+            strings(&split, "s_0"),
+            vec![Some("a,b,c,d"), Some("x"), None, Some("p,q")]
+        );
+        assert_eq!(split.null_count("s_1").unwrap(), 4);
+        assert!(matches!(
+            df.string_split_column("s", ',', 0),
+            Err(Error::InvalidArgument(_))
+        ));
+        df.clone_column("n", "s_1").unwrap();
+        assert!(matches!(
+            df.string_split_column("s", ',', 2),
+            Err(Error::AlreadyExists(_))
+        ));
+        assert_eq!(df.names(), vec!["s", "n", "s_1"]);
+    }
+
+    #[test]
+    fn test_concat_string_columns() {
+        let mut df = str_df(vec![Some("a"), None, Some("c"), None]);
+        df.add_series0(
+            "t",
+            Utf8Array::<i64>::from(vec![Some("x"), Some("y"), None, None]).boxed(),
+        )
+        .unwrap();
+        let orig = df.clone();
+        df.concat_string_columns("s", "t", "-", "strict", true)
+            .unwrap();
+        df.concat_string_columns("s", "t", "-", "lenient", false)
+            .unwrap();
+        df.concat_string_columns("s", "t", "", "empty", true)
+            .unwrap();
+        assert_eq!(strings(&df, "strict"), vec![Some("a-x"), None, None, None]);
+        assert_eq!(
+            strings(&df, "lenient"),
+            vec![Some("a-x"), Some("y"), Some("c"), None]
+        );
+        assert_eq!(strings(&df, "empty"), vec![Some("ax"), None, None, None]);
+        assert_eq!(df.fields()[2].data_type, DataType::Utf8);
+        assert!(df.select(&["s", "t"]).unwrap() == orig);
+        assert!(matches!(
+            df.concat_string_columns("s", "t", "", "strict", true),
+            Err(Error::AlreadyExists(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn test_string_extract_regex() {
+        let mut df = str_df(vec![Some("id=42;"), Some("none"), None, Some("id=7")]);
+        df.string_extract_regex("s", r"id=(\d+)", 0, "whole")
+            .unwrap();
+        df.string_extract_regex("s", r"id=(\d+)", 1, "group")
+            .unwrap();
+        assert_eq!(
+            strings(&df, "whole"),
+            vec![Some("id=42"), None, None, Some("id=7")]
+        );
+        assert_eq!(
+            strings(&df, "group"),
+            vec![Some("42"), None, None, Some("7")]
+        );
+        assert!(matches!(
+            df.string_extract_regex("s", r"id=(\d+)", 2, "x"),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            df.string_extract_regex("s", r"id=(", 0, "x"),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(df.get_column_index("x").is_none());
+    }
+}
@@ -0,0 +1,397 @@
+#[cfg(feature = "arrow2_ih")]
+extern crate arrow2_ih as arrow2;
+
+use crate::df::DataFrame;
+use crate::{naive_from_timestamp, Error};
+use arrow2::array::{Int32Array, Int64Array};
+use arrow2::datatypes::{DataType, TimeUnit};
+use chrono::{Datelike, NaiveDateTime, Timelike};
+
+impl DataFrame {
+    /// Extract year of a Timestamp column values (UTC) into a new Int32 column
+    #[inline]
+    pub fn extract_year(&mut self, src: &str, dest: &str) -> Result<(), Error> {
+        self.extract_datetime_part(src, dest, |dt| dt.year())
+    }
+    /// Extract month (1-12) of a Timestamp column values (UTC) into a new Int32 column
+    #[inline]
+    pub fn extract_month(&mut self, src: &str, dest: &str) -> Result<(), Error> {
+        self.extract_datetime_part(src, dest, |dt| dt.month().try_into().unwrap_or_default())
+    }
+    /// Extract day of month (1-31) of a Timestamp column values (UTC) into a new Int32 column
+    #[inline]
+    pub fn extract_day(&mut self, src: &str, dest: &str) -> Result<(), Error> {
+        self.extract_datetime_part(src, dest, |dt| dt.day().try_into().unwrap_or_default())
+    }
+    /// Extract hour (0-23) of a Timestamp column values (UTC) into a new Int32 column
+    #[inline]
+    pub fn extract_hour(&mut self, src: &str, dest: &str) -> Result<(), Error> {
+        self.extract_datetime_part(src, dest, |dt| dt.hour().try_into().unwrap_or_default())
+    }
+    /// Extract minute (0-59) of a Timestamp column values into a new Int32 column
+    #[inline]
+    pub fn extract_minute(&mut self, src: &str, dest: &str) -> Result<(), Error> {
+        self.extract_datetime_part(src, dest, |dt| dt.minute().try_into().unwrap_or_default())
+    }
+    /// Extract second (0-59) of a Timestamp column values into a new Int32 column
+    #[inline]
+    pub fn extract_second(&mut self, src: &str, dest: &str) -> Result<(), Error> {
+        self.extract_datetime_part(src, dest, |dt| dt.second().try_into().unwrap_or_default())
+    }
+    /// Extract microseconds of second (0-999999) of a Timestamp column values into a new Int32
+    /// column
+    #[inline]
+    pub fn extract_subsecond_micros(&mut self, src: &str, dest: &str) -> Result<(), Error> {
+        self.extract_datetime_part(src, dest, |dt| {
+            // leap second nanos (>= 1_000_000_000) are clamped
+            (dt.timestamp_subsec_micros().min(999_999))
+                .try_into()
+                .unwrap_or_default()
+        })
+    }
+    /// Convert a Timestamp column to another IANA time zone (e.g. "America/New_York")
+    ///
+    /// The values are considered as wall-clock times of the source time zone (UTC for the
+    /// columns with no time zone) and are shifted to the wall-clock times of the target one.
+    /// Ambiguous source times (DST fall-back) are resolved to the earlier instant, non-existent
+    /// ones (DST spring-forward gap) produce an error
+    #[cfg(feature = "chrono-tz")]
+    pub fn convert_timezone(&mut self, name: &str, target_tz: &str) -> Result<(), Error> {
+        use chrono::{LocalResult, Offset, TimeZone};
+        fn parse_tz(tz: &str) -> Result<chrono_tz::Tz, Error> {
+            tz.parse()
+                .map_err(|_| Error::InvalidArgument(format!("invalid time zone: {}", tz)))
+        }
+        fn local_offset(tz: chrono_tz::Tz, local: &NaiveDateTime) -> Result<i64, Error> {
+            let offset = match tz.offset_from_local_datetime(local) {
+                LocalResult::Single(offset) => offset.fix().local_minus_utc(),
+                // the larger offset gives the earlier UTC instant
+                LocalResult::Ambiguous(a, b) => {
+                    a.fix().local_minus_utc().max(b.fix().local_minus_utc())
+                }
+                LocalResult::None => {
+                    return Err(Error::other(format!(
+                        "non-existent local time {} in {}",
+                        local, tz
+                    )));
+                }
+            };
+            Ok(i64::from(offset))
+        }
+        fn utc_offset(tz: chrono_tz::Tz, utc: &NaiveDateTime) -> i64 {
+            i64::from(tz.offset_from_utc_datetime(utc).fix().local_minus_utc())
+        }
+        let target = parse_tz(target_tz)?;
+        let (values, time_unit) = self.get_timestamp(name)?;
+        let source = if let Some((_, DataType::Timestamp(_, Some(tz)))) = self.get_series(name) {
+            parse_tz(tz)?
+        } else {
+            chrono_tz::UTC
+        };
+        let multiplier = match time_unit {
+            TimeUnit::Second => 1,
+            TimeUnit::Millisecond => 1_000,
+            TimeUnit::Microsecond => 1_000_000,
+            TimeUnit::Nanosecond => 1_000_000_000,
+        };
+        let mut dt: Vec<Option<i64>> = Vec::with_capacity(values.len());
+        for value in values {
+            dt.push(if let Some(ts) = value {
+                let local = naive_from_timestamp(*ts, &time_unit)
+                    .ok_or_else(|| Error::other(format!("invalid timestamp: {}", ts)))?;
+                let source_offset = local_offset(source, &local)?;
+                let utc = local
+                    .checked_sub_signed(chrono::Duration::seconds(source_offset))
+                    .ok_or(Error::Overflow)?;
+                let shift = (utc_offset(target, &utc) - source_offset)
+                    .checked_mul(multiplier)
+                    .ok_or(Error::Overflow)?;
+                Some(ts.checked_add(shift).ok_or(Error::Overflow)?)
+            } else {
+                None
+            });
+        }
+        let data_type = DataType::Timestamp(time_unit, Some(target_tz.to_owned()));
+        self.set_data_type(name, data_type.clone())?;
+        self.replace_series(name, Int64Array::from(dt).to(data_type).boxed())
+    }
+    /// Calculate the signed difference (later - earlier) of two Timestamp columns into a new
+    /// Duration column of the given time unit
+    ///
+    /// Nulls in any of the sources produce nulls
+    pub fn timestamp_diff(
+        &mut self,
+        earlier: &str,
+        later: &str,
+        dest: &str,
+        unit: TimeUnit,
+    ) -> Result<(), Error> {
+        let (a, a_unit) = self.get_timestamp(earlier)?;
+        let (b, b_unit) = self.get_timestamp(later)?;
+        let mut dt: Vec<Option<i64>> = Vec::with_capacity(a.len());
+        for (x, y) in a.iter().zip(b.iter()) {
+            dt.push(if let (Some(x), Some(y)) = (x, y) {
+                let x = naive_from_timestamp(*x, &a_unit)
+                    .ok_or_else(|| Error::other(format!("invalid timestamp: {}", x)))?;
+                let y = naive_from_timestamp(*y, &b_unit)
+                    .ok_or_else(|| Error::other(format!("invalid timestamp: {}", y)))?;
+                let diff = y.signed_duration_since(x);
+                Some(
+                    match unit {
+                        TimeUnit::Second => Some(diff.num_seconds()),
+                        TimeUnit::Millisecond => Some(diff.num_milliseconds()),
+                        TimeUnit::Microsecond => diff.num_microseconds(),
+                        TimeUnit::Nanosecond => diff.num_nanoseconds(),
+                    }
+                    .ok_or(Error::Overflow)?,
+                )
+            } else {
+                None
+            });
+        }
+        let data_type = DataType::Duration(unit);
+        self.add_series(
+            dest,
+            Int64Array::from(dt).to(data_type.clone()).boxed(),
+            Some(data_type),
+            None,
+        )
+    }
+    fn extract_datetime_part<F>(&mut self, src: &str, dest: &str, func: F) -> Result<(), Error>
+    where
+        F: Fn(&NaiveDateTime) -> i32,
+    {
+        let (values, time_unit) = self.get_timestamp(src)?;
+        let result: Int32Array = values
+            .iter()
+            .map(|v| {
+                v.and_then(|ts| naive_from_timestamp(*ts, &time_unit))
+                    .map(|dt| func(&dt))
+            })
+            .collect();
+        self.add_series0(dest, result.boxed())
+    }
+    fn get_timestamp(&self, name: &str) -> Result<(&Int64Array, TimeUnit), Error> {
+        let (series, data_type) = self
+            .get_series(name)
+            .ok_or_else(|| Error::NotFound(name.to_owned()))?;
+        if let DataType::Timestamp(time_unit, _) = data_type {
+            let values = series
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .ok_or(Error::TypeMismatch)?;
+            Ok((values, *time_unit))
+        } else {
+            Err(Error::TypeMismatch)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ts_df(values: Vec<Option<i64>>, time_unit: TimeUnit, tz: Option<&str>) -> DataFrame {
+        let mut df = DataFrame::new0();
+        let data_type = DataType::Timestamp(time_unit, tz.map(ToOwned::to_owned));
+        df.add_series(
+            "t",
+            Int64Array::from(values).to(data_type.clone()).boxed(),
+            Some(data_type),
+            None,
+        )
+        .unwrap();
+        df
+    }
+
+    fn ints(df: &DataFrame, name: &str) -> Vec<Option<i32>> {
+        let values: &Int32Array = df
+            .get_series(name)
+            .unwrap()
+            .0
+            .as_any()
+            .downcast_ref()
+            .unwrap();
+        values.iter().map(|v| v.copied()).collect()
+    }
+
+    #[test]
+    fn test_extract_date_parts() {
+        let values = vec![
+            Some(1_709_249_400_000),
+            Some(951_782_400_000),
+            None,
+            Some(-1_000),
+            Some(1_677_628_800_000),
+        ];
+        // the time zone is not applied, the values are always UTC
+        for tz in [None, Some("America/New_York")] {
+            let mut df = ts_df(values.clone(), TimeUnit::Millisecond, tz);
+            df.extract_year("t", "year").unwrap();
+            df.extract_month("t", "month").unwrap();
+            df.extract_day("t", "day").unwrap();
+            assert_eq!(
+                ints(&df, "year"),
+                vec![Some(2024), Some(2000), None, Some(1969), Some(2023)]
+            );
+            assert_eq!(
+                ints(&df, "month"),
+                vec![Some(2), Some(2), None, Some(12), Some(3)]
+            );
+            assert_eq!(
+                ints(&df, "day"),
+                vec![Some(29), Some(29), None, Some(31), Some(1)]
+            );
+            assert_eq!(df.fields()[1].data_type, DataType::Int32);
+        }
+        let mut df = ts_df(vec![Some(951_782_400)], TimeUnit::Second, None);
+        df.extract_day("t", "day").unwrap();
+        assert_eq!(ints(&df, "day"), vec![Some(29)]);
+        assert!(matches!(
+            df.extract_day("day", "x"),
+            Err(Error::TypeMismatch)
+        ));
+        assert!(matches!(
+            df.extract_day("t", "day"),
+            Err(Error::AlreadyExists(_))
+        ));
+    }
+
+    #[test]
+    fn test_extract_time_parts() {
+        let mut df = ts_df(
+            vec![
+                Some(1_709_164_800_000_000),
+                Some(1_709_251_199_999_999),
+                Some(1_709_251_199_000_000),
+                Some(1_709_207_245_000_123),
+                None,
+            ],
+            TimeUnit::Microsecond,
+            None,
+        );
+        df.extract_hour("t", "h").unwrap();
+        df.extract_minute("t", "m").unwrap();
+        df.extract_second("t", "s").unwrap();
+        df.extract_subsecond_micros("t", "us").unwrap();
+        assert_eq!(
+            ints(&df, "h"),
+            vec![Some(0), Some(23), Some(23), Some(11), None]
+        );
+        assert_eq!(
+            ints(&df, "m"),
+            vec![Some(0), Some(59), Some(59), Some(47), None]
+        );
+        assert_eq!(
+            ints(&df, "s"),
+            vec![Some(0), Some(59), Some(59), Some(25), None]
+        );
+        assert_eq!(
+            ints(&df, "us"),
+            vec![Some(0), Some(999_999), Some(0), Some(123), None]
+        );
+        let mut df = ts_df(
+            vec![Some(1_709_251_199_999_999_999)],
+            TimeUnit::Nanosecond,
+            None,
+        );
+        df.extract_subsecond_micros("t", "us").unwrap();
+        df.extract_second("t", "s").unwrap();
+        assert_eq!(ints(&df, "us"), vec![Some(999_999)]);
+        assert_eq!(ints(&df, "s"), vec![Some(59)]);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono-tz")]
+    fn test_convert_timezone() {
+        fn values(df: &DataFrame) -> Vec<Option<i64>> {
+            df.get_int64("t")
+                .unwrap()
+                .iter()
+                .map(|v| v.copied())
+                .collect()
+        }
+        // 2023-03-12 06:59:59 and 07:00:00 UTC, the DST switch in New York
+        let mut df = ts_df(
+            vec![Some(1_678_604_399), Some(1_678_604_400), None],
+            TimeUnit::Second,
+            None,
+        );
+        df.convert_timezone("t", "America/New_York").unwrap();
+        assert_eq!(
+            df.fields()[0].data_type,
+            DataType::Timestamp(TimeUnit::Second, Some("America/New_York".to_owned()))
+        );
+        // 01:59:59 EST and 03:00:00 EDT
+        assert_eq!(
+            values(&df),
+            vec![Some(1_678_586_399), Some(1_678_590_000), None]
+        );
+        df.convert_timezone("t", "UTC").unwrap();
+        assert_eq!(
+            values(&df),
+            vec![Some(1_678_604_399), Some(1_678_604_400), None]
+        );
+        // 2023-11-05 01:30:00 is ambiguous in New York, the earlier instant (EDT) is used
+        let mut df = ts_df(
+            vec![Some(1_699_147_800_000)],
+            TimeUnit::Millisecond,
+            Some("America/New_York"),
+        );
+        df.convert_timezone("t", "Etc/UTC").unwrap();
+        assert_eq!(values(&df), vec![Some(1_699_162_200_000)]);
+        // 2023-03-12 02:30:00 does not exist in New York
+        let mut df = ts_df(
+            vec![Some(1_678_588_200)],
+            TimeUnit::Second,
+            Some("America/New_York"),
+        );
+        assert!(df.convert_timezone("t", "UTC").is_err());
+        assert_eq!(values(&df), vec![Some(1_678_588_200)]);
+        assert!(matches!(
+            df.convert_timezone("t", "Mars/Olympus"),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_timestamp_diff() {
+        let mut df = ts_df(
+            vec![Some(1_000), Some(5_000), None, Some(0)],
+            TimeUnit::Millisecond,
+            None,
+        );
+        df.add_series0(
+            "u",
+            Int64Array::from(vec![Some(3), Some(2), Some(1), None])
+                .to(DataType::Timestamp(TimeUnit::Second, None))
+                .boxed(),
+        )
+        .unwrap();
+        df.timestamp_diff("t", "u", "d", TimeUnit::Millisecond)
+            .unwrap();
+        df.timestamp_diff("t", "u", "ds", TimeUnit::Second).unwrap();
+        assert_eq!(
+            df.fields()[2].data_type,
+            DataType::Duration(TimeUnit::Millisecond)
+        );
+        let diff = |name: &str| -> Vec<Option<i64>> {
+            let values: &Int64Array = df
+                .get_series(name)
+                .unwrap()
+                .0
+                .as_any()
+                .downcast_ref()
+                .unwrap();
+            values.iter().map(|v| v.copied()).collect()
+        };
+        assert_eq!(diff("d"), vec![Some(2_000), Some(-3_000), None, None]);
+        assert_eq!(diff("ds"), vec![Some(2), Some(-3), None, None]);
+        df.add_series0("i", Int64Array::from_vec(vec![0; 4]).boxed())
+            .unwrap();
+        assert!(matches!(
+            df.timestamp_diff("t", "i", "x", TimeUnit::Second),
+            Err(Error::TypeMismatch)
+        ));
+    }
+}
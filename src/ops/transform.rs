@@ -0,0 +1,704 @@
+#[cfg(feature = "arrow2_ih")]
+extern crate arrow2_ih as arrow2;
+
+use crate::df::DataFrame;
+use crate::Error;
+use arrow2::array::{Array, Float64Array, Int64Array};
+use arrow2::bitmap::Bitmap;
+use arrow2::compute;
+use arrow2::compute::arithmetics::basic;
+use arrow2::datatypes::DataType;
+
+impl DataFrame {
+    /// Scale a Float64 column to [0.0, 1.0] in-place
+    ///
+    /// If all values are equal, they are set to 0.0. Nulls remain null
+    pub fn normalize_minmax(&mut self, name: &str) -> Result<(), Error> {
+        if let (Some(min), Some(max)) = self.column_range_float(name)? {
+            let range = max - min;
+            if range == 0.0 {
+                self.apply_float(name, |v| v.map(|_| 0.0))
+            } else {
+                self.apply_float(name, |v| v.map(|n| (n - min) / range))
+            }
+        } else {
+            Ok(())
+        }
+    }
+    /// Standardize a Float64 column in-place: (x - mean) / std, population standard deviation
+    /// is used
+    ///
+    /// If the standard deviation is zero, values are set to 0.0. Nulls remain null
+    pub fn normalize_zscore(&mut self, name: &str) -> Result<(), Error> {
+        if let (Some(mean), Some(std)) = (self.column_mean(name)?, self.column_std(name, 0)?) {
+            if std == 0.0 {
+                self.apply_float(name, |v| v.map(|_| 0.0))
+            } else {
+                self.apply_float(name, |v| v.map(|n| (n - mean) / std))
+            }
+        } else {
+            Ok(())
+        }
+    }
+    /// Clamp values of a Float64 column in-place
+    ///
+    /// Nulls and NaNs remain unchanged. No-op if both bounds are not set
+    pub fn clip(&mut self, name: &str, min: Option<f64>, max: Option<f64>) -> Result<(), Error> {
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                return Err(Error::InvalidArgument(format!(
+                    "min ({}) is greater than max ({})",
+                    min, max
+                )));
+            }
+        }
+        if min.is_none() && max.is_none() {
+            // check the column exists and has the correct type
+            self.get_float64(name)?;
+            return Ok(());
+        }
+        self.apply_float(name, |v| {
+            v.map(|n| match (min, max) {
+                (Some(min), _) if n < min => min,
+                (_, Some(max)) if n > max => max,
+                _ => n,
+            })
+        })
+    }
+    /// Replace values of a Float64 or Int64 column with their absolute values
+    ///
+    /// Returns [`Error::Overflow`] if an Int64 column contains i64::MIN
+    pub fn abs_column(&mut self, name: &str) -> Result<(), Error> {
+        self.map_signed(name, f64::abs, i64::checked_abs)
+    }
+    /// Negate values of a Float64 or Int64 column
+    ///
+    /// Returns [`Error::Overflow`] if an Int64 column contains i64::MIN
+    pub fn negate_column(&mut self, name: &str) -> Result<(), Error> {
+        self.map_signed(name, |v| -v, i64::checked_neg)
+    }
+    fn map_signed(
+        &mut self,
+        name: &str,
+        func_float: fn(f64) -> f64,
+        func_int: fn(i64) -> Option<i64>,
+    ) -> Result<(), Error> {
+        let (_, data_type) = self
+            .get_series(name)
+            .ok_or_else(|| Error::NotFound(name.to_owned()))?;
+        match data_type {
+            DataType::Float64 => self.apply_float(name, |v| v.map(func_float)),
+            DataType::Int64 => self.apply_int_checked(name, func_int),
+            _ => Err(Error::TypeMismatch),
+        }
+    }
+    /// Round values of a Float64 column to the specified number of decimal places
+    pub fn round_column(&mut self, name: &str, decimal_places: u32) -> Result<(), Error> {
+        let factor = 10f64.powi(i32::try_from(decimal_places).unwrap_or(i32::MAX));
+        if factor.is_finite() {
+            self.apply_float(name, |v| {
+                v.map(|n| {
+                    let rounded = (n * factor).round() / factor;
+                    if rounded.is_finite() {
+                        rounded
+                    } else {
+                        n
+                    }
+                })
+            })
+        } else {
+            // all values already have less decimal places
+            self.get_float64(name)?;
+            Ok(())
+        }
+    }
+    /// Round values of a Float64 column down
+    #[inline]
+    pub fn floor_column(&mut self, name: &str) -> Result<(), Error> {
+        self.apply_float(name, |v| v.map(f64::floor))
+    }
+    /// Round values of a Float64 column up
+    #[inline]
+    pub fn ceil_column(&mut self, name: &str) -> Result<(), Error> {
+        self.apply_float(name, |v| v.map(f64::ceil))
+    }
+    /// Natural logarithm of a Float64 column, non-positive values become nulls
+    #[inline]
+    pub fn log_column(&mut self, name: &str) -> Result<(), Error> {
+        self.apply_float(name, |v| v.filter(|n| *n > 0.0).map(f64::ln))
+    }
+    /// Exponent (e^x) of a Float64 column
+    #[inline]
+    pub fn exp_column(&mut self, name: &str) -> Result<(), Error> {
+        self.apply_float(name, |v| v.map(f64::exp))
+    }
+    /// Square root of a Float64 column, negative values become nulls
+    #[inline]
+    pub fn sqrt_column(&mut self, name: &str) -> Result<(), Error> {
+        self.apply_float(name, |v| v.filter(|n| *n >= 0.0).map(f64::sqrt))
+    }
+    /// Raise values of a Float64 column to the power
+    #[inline]
+    pub fn pow_column(&mut self, name: &str, exponent: f64) -> Result<(), Error> {
+        self.apply_float(name, |v| v.map(|n| n.powf(exponent)))
+    }
+    /// Add a constant to a Float64 column
+    #[inline]
+    pub fn add_scalar(&mut self, name: &str, value: f64) -> Result<(), Error> {
+        self.apply_float(name, |v| v.map(|n| n + value))
+    }
+    /// Subtract a constant from a Float64 column
+    #[inline]
+    pub fn subtract_scalar(&mut self, name: &str, value: f64) -> Result<(), Error> {
+        self.apply_float(name, |v| v.map(|n| n - value))
+    }
+    /// Multiply a Float64 column by a constant
+    #[inline]
+    pub fn multiply_scalar(&mut self, name: &str, value: f64) -> Result<(), Error> {
+        self.apply_float(name, |v| v.map(|n| n * value))
+    }
+    /// Divide a Float64 column by a constant, division by zero produces nulls
+    #[inline]
+    pub fn divide_scalar(&mut self, name: &str, value: f64) -> Result<(), Error> {
+        self.apply_float(name, |v| v.filter(|_| value != 0.0).map(|n| n / value))
+    }
+    /// Add a constant to an Int64 column
+    ///
+    /// Returns [`Error::Overflow`] if any result does not fit into i64, the column is not
+    /// modified in this case
+    #[inline]
+    pub fn add_scalar_int(&mut self, name: &str, value: i64) -> Result<(), Error> {
+        self.apply_int_checked(name, |n| n.checked_add(value))
+    }
+    /// Subtract a constant from an Int64 column
+    ///
+    /// Returns [`Error::Overflow`] if any result does not fit into i64, the column is not
+    /// modified in this case
+    #[inline]
+    pub fn subtract_scalar_int(&mut self, name: &str, value: i64) -> Result<(), Error> {
+        self.apply_int_checked(name, |n| n.checked_sub(value))
+    }
+    /// Multiply an Int64 column by a constant
+    ///
+    /// Returns [`Error::Overflow`] if any result does not fit into i64, the column is not
+    /// modified in this case
+    #[inline]
+    pub fn multiply_scalar_int(&mut self, name: &str, value: i64) -> Result<(), Error> {
+        self.apply_int_checked(name, |n| n.checked_mul(value))
+    }
+    /// Divide an Int64 column by a constant (integer division), division by zero produces nulls
+    ///
+    /// Returns [`Error::Overflow`] if any result does not fit into i64 (i64::MIN / -1), the
+    /// column is not modified in this case
+    pub fn divide_scalar_int(&mut self, name: &str, value: i64) -> Result<(), Error> {
+        if value == 0 {
+            self.apply_int(name, |_| None)
+        } else {
+            self.apply_int_checked(name, |n| n.checked_div(value))
+        }
+    }
+    fn apply_int_checked<F>(&mut self, name: &str, func: F) -> Result<(), Error>
+    where
+        F: Fn(i64) -> Option<i64>,
+    {
+        if self
+            .get_int64(name)?
+            .iter()
+            .flatten()
+            .any(|v| func(*v).is_none())
+        {
+            return Err(Error::Overflow);
+        }
+        self.apply_int(name, |v| v.and_then(&func))
+    }
+    /// Add two Float64 columns element-wise: dest = a + b
+    ///
+    /// If the destination column exists, it is replaced, regardless of its type
+    #[inline]
+    pub fn add_columns(&mut self, a: &str, b: &str, dest: &str) -> Result<(), Error> {
+        self.arithmetic_columns(a, b, dest, basic::add)
+    }
+    /// Subtract two Float64 columns element-wise: dest = a - b
+    ///
+    /// If the destination column exists, it is replaced, regardless of its type
+    #[inline]
+    pub fn subtract_columns(&mut self, a: &str, b: &str, dest: &str) -> Result<(), Error> {
+        self.arithmetic_columns(a, b, dest, basic::sub)
+    }
+    /// Multiply two Float64 columns element-wise: dest = a * b
+    ///
+    /// If the destination column exists, it is replaced, regardless of its type
+    #[inline]
+    pub fn multiply_columns(&mut self, a: &str, b: &str, dest: &str) -> Result<(), Error> {
+        self.arithmetic_columns(a, b, dest, basic::mul)
+    }
+    /// Divide two Float64 columns element-wise: dest = a / b, division by zero produces nulls
+    ///
+    /// If the destination column exists, it is replaced, regardless of its type
+    #[inline]
+    pub fn divide_columns(&mut self, a: &str, b: &str, dest: &str) -> Result<(), Error> {
+        self.arithmetic_columns(a, b, dest, |x, y| {
+            let result = basic::div(x, y);
+            let non_zero: Bitmap = y.values().iter().map(|v| *v != 0.0).collect();
+            let validity = match result.validity() {
+                Some(validity) => validity & &non_zero,
+                None => non_zero,
+            };
+            result.with_validity(Some(validity))
+        })
+    }
+    fn arithmetic_columns<F>(&mut self, a: &str, b: &str, dest: &str, func: F) -> Result<(), Error>
+    where
+        F: Fn(&Float64Array, &Float64Array) -> Float64Array,
+    {
+        let result = func(self.get_float64(a)?, self.get_float64(b)?).boxed();
+        if self.get_column_index(dest).is_some() {
+            self.set_data_type(dest, DataType::Float64)?;
+            self.replace_series(dest, result)
+        } else {
+            self.add_series0(dest, result)
+        }
+    }
+    /// Fill nulls of a column with the last previous non-null value
+    ///
+    /// Leading nulls remain null
+    #[inline]
+    pub fn forward_fill(&mut self, name: &str) -> Result<(), Error> {
+        self.fill_from_valid(name, false)
+    }
+    /// Fill nulls of a column with the next non-null value
+    ///
+    /// Trailing nulls remain null
+    #[inline]
+    pub fn backward_fill(&mut self, name: &str) -> Result<(), Error> {
+        self.fill_from_valid(name, true)
+    }
+    /// Fill null gaps of a Float64 column with linear interpolation between the surrounding
+    /// non-null values, row indices are used as the x-axis
+    ///
+    /// Leading and trailing nulls remain null
+    #[allow(clippy::cast_precision_loss)]
+    pub fn interpolate_linear(&mut self, name: &str) -> Result<(), Error> {
+        let values = self.get_float64(name)?;
+        if values.null_count() == 0 {
+            return Ok(());
+        }
+        let mut result: Vec<Option<f64>> = values.iter().map(|v| v.copied()).collect();
+        let mut prev: Option<usize> = None;
+        for i in 0..result.len() {
+            if let Some(y1) = result[i] {
+                if let Some(p) = prev {
+                    if i - p > 1 {
+                        let y0 = result[p].unwrap_or_default();
+                        let step = (y1 - y0) / (i - p) as f64;
+                        for (k, v) in result.iter_mut().enumerate().take(i).skip(p + 1) {
+                            *v = Some(y0 + step * (k - p) as f64);
+                        }
+                    }
+                }
+                prev = Some(i);
+            }
+        }
+        self.replace_series(name, Float64Array::from(result).boxed())
+    }
+    /// Replace all occurrences of a value in a Float64 column, None replaces with nulls
+    ///
+    /// NaN can be used as the value to search. Returns the number of replaced values
+    pub fn replace_float_value(
+        &mut self,
+        name: &str,
+        old: f64,
+        new: Option<f64>,
+    ) -> Result<usize, Error> {
+        let matches = |v: f64| if old.is_nan() { v.is_nan() } else { v == old };
+        let count = self
+            .get_float64(name)?
+            .iter()
+            .flatten()
+            .filter(|v| matches(**v))
+            .count();
+        if count > 0 {
+            self.apply_float(name, |v| match v {
+                Some(n) if matches(n) => new,
+                _ => v,
+            })?;
+        }
+        Ok(count)
+    }
+    /// Replace all occurrences of a value in an Int64 column, None replaces with nulls
+    ///
+    /// Returns the number of replaced values
+    pub fn replace_int_value(
+        &mut self,
+        name: &str,
+        old: i64,
+        new: Option<i64>,
+    ) -> Result<usize, Error> {
+        let count = self
+            .get_int64(name)?
+            .iter()
+            .flatten()
+            .filter(|v| **v == old)
+            .count();
+        if count > 0 {
+            self.apply_int(name, |v| if v == Some(old) { new } else { v })?;
+        }
+        Ok(count)
+    }
+    /// Replace all occurrences of a value in a Utf8/LargeUtf8 column, None replaces with nulls
+    ///
+    /// Returns the number of replaced values
+    pub fn replace_str_value(
+        &mut self,
+        name: &str,
+        old: &str,
+        new: Option<&str>,
+    ) -> Result<usize, Error> {
+        let count = self
+            .get_utf8_iter(name)?
+            .filter(|v| *v == Some(old))
+            .count();
+        if count > 0 {
+            self.apply_str(name, |v| {
+                if v == Some(old) {
+                    new.map(ToOwned::to_owned)
+                } else {
+                    v.map(ToOwned::to_owned)
+                }
+            })?;
+        }
+        Ok(count)
+    }
+    #[allow(clippy::cast_possible_wrap)]
+    fn fill_from_valid(&mut self, name: &str, backward: bool) -> Result<(), Error> {
+        let (serie, _) = self
+            .get_series(name)
+            .ok_or_else(|| Error::NotFound(name.to_owned()))?;
+        if serie.null_count() == 0 {
+            return Ok(());
+        }
+        let len = serie.len();
+        let mut indices: Vec<Option<i64>> = vec![None; len];
+        let mut last = None;
+        let positions: Box<dyn Iterator<Item = usize>> = if backward {
+            Box::new((0..len).rev())
+        } else {
+            Box::new(0..len)
+        };
+        for i in positions {
+            if serie.is_valid(i) {
+                last = Some(i as i64);
+            }
+            indices[i] = last;
+        }
+        let result = compute::take::take(serie.as_ref(), &Int64Array::from(indices))?;
+        self.replace_series(name, result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow2::array::Utf8Array;
+
+    fn float_df(values: Vec<Option<f64>>) -> DataFrame {
+        let mut df = DataFrame::new0();
+        df.add_series0("x", Float64Array::from(values).boxed())
+            .unwrap();
+        df
+    }
+
+    fn floats(df: &DataFrame) -> Vec<Option<f64>> {
+        df.get_float64("x")
+            .unwrap()
+            .iter()
+            .map(|v| v.copied())
+            .collect()
+    }
+
+    #[test]
+    fn test_normalize_minmax() {
+        let mut df = float_df(vec![Some(2.0), None, Some(6.0), Some(3.0)]);
+        df.normalize_minmax("x").unwrap();
+        assert_eq!(floats(&df), vec![Some(0.0), None, Some(1.0), Some(0.25)]);
+        let mut df = float_df(vec![Some(5.0), Some(5.0), None]);
+        df.normalize_minmax("x").unwrap();
+        assert_eq!(floats(&df), vec![Some(0.0), Some(0.0), None]);
+        let mut df = float_df(vec![None, None]);
+        df.normalize_minmax("x").unwrap();
+        assert_eq!(floats(&df), vec![None, None]);
+    }
+    #[test]
+    fn test_normalize_zscore() {
+        let mut df = float_df(vec![
+            Some(2.0),
+            Some(4.0),
+            Some(4.0),
+            None,
+            Some(4.0),
+            Some(5.0),
+            Some(5.0),
+            Some(7.0),
+            Some(9.0),
+        ]);
+        df.normalize_zscore("x").unwrap();
+        let result = floats(&df);
+        assert!(result[3].is_none());
+        assert!((result[0].unwrap() + 1.5).abs() < 1e-10);
+        assert!((result[8].unwrap() - 2.0).abs() < 1e-10);
+        assert!(df.column_mean("x").unwrap().unwrap().abs() < 1e-10);
+        assert!((df.column_std("x", 0).unwrap().unwrap() - 1.0).abs() < 1e-10);
+        let mut df = float_df(vec![Some(3.0), None]);
+        df.normalize_zscore("x").unwrap();
+        assert_eq!(floats(&df), vec![Some(0.0), None]);
+    }
+
+    #[test]
+    fn test_clip() {
+        let values = vec![Some(-5.0), None, Some(0.5), Some(5.0)];
+        let mut df = float_df(values.clone());
+        df.clip("x", Some(-1.0), Some(1.0)).unwrap();
+        assert_eq!(floats(&df), vec![Some(-1.0), None, Some(0.5), Some(1.0)]);
+        let mut df = float_df(values.clone());
+        df.clip("x", Some(0.0), None).unwrap();
+        assert_eq!(floats(&df), vec![Some(0.0), None, Some(0.5), Some(5.0)]);
+        let mut df = float_df(values.clone());
+        df.clip("x", None, Some(0.0)).unwrap();
+        assert_eq!(floats(&df), vec![Some(-5.0), None, Some(0.0), Some(0.0)]);
+        let mut df = float_df(values.clone());
+        df.clip("x", None, None).unwrap();
+        assert_eq!(floats(&df), values);
+        assert!(matches!(
+            df.clip("x", Some(1.0), Some(0.0)),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(df.clip("y", None, None), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn test_abs_negate() {
+        let mut df = float_df(vec![Some(1.0), None, Some(2.5)]);
+        df.add_series0("i", Int64Array::from(vec![Some(-3), None, Some(4)]).boxed())
+            .unwrap();
+        let orig = df.clone();
+        df.abs_column("x").unwrap();
+        assert!(df == orig);
+        df.abs_column("i").unwrap();
+        assert_eq!(
+            df.get_int64("i")
+                .unwrap()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(3), None, Some(4)]
+        );
+        let mut df = orig.clone();
+        df.negate_column("x").unwrap();
+        df.negate_column("i").unwrap();
+        assert_eq!(floats(&df), vec![Some(-1.0), None, Some(-2.5)]);
+        df.negate_column("x").unwrap();
+        df.negate_column("i").unwrap();
+        assert!(df == orig);
+        df.add_series0(
+            "s",
+            arrow2::array::Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed(),
+        )
+        .unwrap();
+        assert!(matches!(df.abs_column("s"), Err(Error::TypeMismatch)));
+        let mut df = DataFrame::new0();
+        df.add_series0("i", Int64Array::from_vec(vec![1, i64::MIN]).boxed())
+            .unwrap();
+        assert!(matches!(df.negate_column("i"), Err(Error::Overflow)));
+        assert_eq!(df.get_int64("i").unwrap().value(0), 1);
+    }
+
+    #[test]
+    fn test_math_functions() {
+        let mut df = float_df(vec![Some(1.256), Some(-1.5), None]);
+        df.round_column("x", 2).unwrap();
+        assert_eq!(floats(&df), vec![Some(1.26), Some(-1.5), None]);
+        df.round_column("x", 0).unwrap();
+        assert_eq!(floats(&df), vec![Some(1.0), Some(-2.0), None]);
+        let mut df = float_df(vec![Some(1.5), Some(-1.5), None]);
+        df.floor_column("x").unwrap();
+        assert_eq!(floats(&df), vec![Some(1.0), Some(-2.0), None]);
+        let mut df = float_df(vec![Some(1.5), Some(-1.5), None]);
+        df.ceil_column("x").unwrap();
+        assert_eq!(floats(&df), vec![Some(2.0), Some(-1.0), None]);
+        let mut df = float_df(vec![Some(0.0), Some(-1.0), Some(1.0), None]);
+        df.log_column("x").unwrap();
+        assert_eq!(floats(&df), vec![None, None, Some(0.0), None]);
+        let mut df = float_df(vec![Some(0.0), Some(1.0), None]);
+        df.exp_column("x").unwrap();
+        let result = floats(&df);
+        assert_eq!(result[0], Some(1.0));
+        assert!((result[1].unwrap() - std::f64::consts::E).abs() < 1e-12);
+        assert!(result[2].is_none());
+        let mut df = float_df(vec![Some(-1.0), Some(0.0), Some(4.0), None]);
+        df.sqrt_column("x").unwrap();
+        assert_eq!(floats(&df), vec![None, Some(0.0), Some(2.0), None]);
+        let mut df = float_df(vec![Some(3.0), Some(-2.0), None]);
+        df.pow_column("x", 2.0).unwrap();
+        assert_eq!(floats(&df), vec![Some(9.0), Some(4.0), None]);
+        df.add_series0("i", Int64Array::from_vec(vec![1, 2, 3]).boxed())
+            .unwrap();
+        assert!(matches!(df.log_column("i"), Err(Error::TypeMismatch)));
+        assert!(matches!(df.round_column("i", 1), Err(Error::TypeMismatch)));
+        assert!(matches!(
+            df.round_column("i", 400),
+            Err(Error::TypeMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_scalar_arithmetic() {
+        let mut df = float_df(vec![Some(1.0), None, Some(-2.0)]);
+        df.add_scalar("x", 1.0).unwrap();
+        assert_eq!(floats(&df), vec![Some(2.0), None, Some(-1.0)]);
+        df.multiply_scalar("x", 2.0).unwrap();
+        assert_eq!(floats(&df), vec![Some(4.0), None, Some(-2.0)]);
+        df.subtract_scalar("x", 1.0).unwrap();
+        assert_eq!(floats(&df), vec![Some(3.0), None, Some(-3.0)]);
+        df.divide_scalar("x", 3.0).unwrap();
+        assert_eq!(floats(&df), vec![Some(1.0), None, Some(-1.0)]);
+        df.divide_scalar("x", 0.0).unwrap();
+        assert_eq!(floats(&df), vec![None, None, None]);
+        let ints = |df: &DataFrame| -> Vec<Option<i64>> {
+            df.get_int64("i")
+                .unwrap()
+                .iter()
+                .map(|v| v.copied())
+                .collect()
+        };
+        df.add_series0("i", Int64Array::from(vec![Some(7), None, Some(-7)]).boxed())
+            .unwrap();
+        df.add_scalar_int("i", 1).unwrap();
+        assert_eq!(ints(&df), vec![Some(8), None, Some(-6)]);
+        df.subtract_scalar_int("i", 2).unwrap();
+        assert_eq!(ints(&df), vec![Some(6), None, Some(-8)]);
+        df.multiply_scalar_int("i", 3).unwrap();
+        assert_eq!(ints(&df), vec![Some(18), None, Some(-24)]);
+        df.divide_scalar_int("i", 4).unwrap();
+        assert_eq!(ints(&df), vec![Some(4), None, Some(-6)]);
+        assert!(matches!(
+            df.add_scalar_int("i", i64::MAX),
+            Err(Error::Overflow)
+        ));
+        assert_eq!(ints(&df), vec![Some(4), None, Some(-6)]);
+        df.divide_scalar_int("i", 0).unwrap();
+        assert_eq!(ints(&df), vec![None, None, None]);
+        assert!(matches!(df.add_scalar("i", 1.0), Err(Error::TypeMismatch)));
+        assert!(matches!(
+            df.add_scalar_int("x", 1),
+            Err(Error::TypeMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_forward_backward_fill() {
+        let values = vec![None, Some(1.0), None, None, Some(4.0), None];
+        let mut df = float_df(values.clone());
+        df.forward_fill("x").unwrap();
+        assert_eq!(
+            floats(&df),
+            vec![None, Some(1.0), Some(1.0), Some(1.0), Some(4.0), Some(4.0)]
+        );
+        let mut df = float_df(values);
+        df.backward_fill("x").unwrap();
+        assert_eq!(
+            floats(&df),
+            vec![Some(1.0), Some(1.0), Some(4.0), Some(4.0), Some(4.0), None]
+        );
+        let mut df = float_df(vec![None, None]);
+        df.forward_fill("x").unwrap();
+        df.backward_fill("x").unwrap();
+        assert_eq!(floats(&df), vec![None, None]);
+        df.add_series0("i", Int64Array::from(vec![Some(3), None]).boxed())
+            .unwrap();
+        df.add_series0("s", Utf8Array::<i32>::from(vec![None, Some("a")]).boxed())
+            .unwrap();
+        df.forward_fill("i").unwrap();
+        df.backward_fill("s").unwrap();
+        assert_eq!(
+            df.get_int64("i").unwrap(),
+            &Int64Array::from_vec(vec![3, 3])
+        );
+        assert_eq!(
+            df.get_utf8_iter("s").unwrap().collect::<Vec<_>>(),
+            [Some("a"), Some("a")]
+        );
+        assert!(matches!(df.forward_fill("y"), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn test_interpolate_linear() {
+        let mut df = float_df(vec![Some(1.0), None, Some(3.0)]);
+        df.interpolate_linear("x").unwrap();
+        assert_eq!(floats(&df), vec![Some(1.0), Some(2.0), Some(3.0)]);
+        let mut df = float_df(vec![None, Some(0.0), None, None, Some(6.0), None]);
+        df.interpolate_linear("x").unwrap();
+        assert_eq!(
+            floats(&df),
+            vec![None, Some(0.0), Some(2.0), Some(4.0), Some(6.0), None]
+        );
+        let mut df = float_df(vec![Some(10.0), None, None, None, Some(2.0)]);
+        df.interpolate_linear("x").unwrap();
+        assert_eq!(
+            floats(&df),
+            vec![Some(10.0), Some(8.0), Some(6.0), Some(4.0), Some(2.0)]
+        );
+        let mut df = float_df(vec![None, None]);
+        df.interpolate_linear("x").unwrap();
+        assert_eq!(floats(&df), vec![None, None]);
+        df.add_series0("i", Int64Array::from(vec![Some(1), None]).boxed())
+            .unwrap();
+        assert!(matches!(
+            df.interpolate_linear("i"),
+            Err(Error::TypeMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_replace_values() {
+        let mut df = float_df(vec![
+            Some(1.0),
+            Some(f64::NAN),
+            None,
+            Some(1.0),
+            Some(f64::NAN),
+        ]);
+        assert_eq!(df.replace_float_value("x", 1.0, Some(2.0)).unwrap(), 2);
+        assert_eq!(df.replace_float_value("x", f64::NAN, None).unwrap(), 2);
+        assert_eq!(floats(&df), vec![Some(2.0), None, None, Some(2.0), None]);
+        assert_eq!(df.replace_float_value("x", 7.0, None).unwrap(), 0);
+        assert_eq!(floats(&df), vec![Some(2.0), None, None, Some(2.0), None]);
+        df.add_series0(
+            "i",
+            Int64Array::from(vec![Some(5), None, Some(6), Some(5), None]).boxed(),
+        )
+        .unwrap();
+        assert_eq!(df.replace_int_value("i", 5, None).unwrap(), 2);
+        assert_eq!(df.replace_int_value("i", 6, Some(0)).unwrap(), 1);
+        assert_eq!(df.replace_int_value("i", 9, Some(0)).unwrap(), 0);
+        assert_eq!(
+            df.get_int64("i").unwrap(),
+            &Int64Array::from(vec![None, None, Some(0), None, None])
+        );
+        df.add_series0(
+            "s",
+            Utf8Array::<i32>::from(vec![Some("a"), Some("b"), None, Some("a"), Some("")]).boxed(),
+        )
+        .unwrap();
+        assert_eq!(df.replace_str_value("s", "a", Some("z")).unwrap(), 2);
+        assert_eq!(df.replace_str_value("s", "b", None).unwrap(), 1);
+        assert_eq!(df.replace_str_value("s", "c", None).unwrap(), 0);
+        assert_eq!(
+            df.get_utf8_iter("s").unwrap().collect::<Vec<_>>(),
+            [Some("z"), None, None, Some("z"), Some("")]
+        );
+        assert!(matches!(
+            df.replace_float_value("i", 1.0, None),
+            Err(Error::TypeMismatch)
+        ));
+    }
+}
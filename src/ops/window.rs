@@ -0,0 +1,934 @@
+#[cfg(feature = "arrow2_ih")]
+extern crate arrow2_ih as arrow2;
+
+use crate::df::{DataFrame, Series};
+use crate::Error;
+use arrow2::array::{new_null_array, Array, Float64Array, Int64Array, PrimitiveArray};
+use arrow2::compute::concatenate::concatenate;
+use arrow2::datatypes::DataType;
+use arrow2::types::NativeType;
+use std::collections::VecDeque;
+
+fn check_window(window: usize, min_periods: usize) -> Result<(), Error> {
+    if window == 0 {
+        Err(Error::InvalidArgument("window must be positive".to_owned()))
+    } else if min_periods > window {
+        Err(Error::InvalidArgument(format!(
+            "min_periods ({}) is greater than window ({})",
+            min_periods, window
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Sliding window accumulator: finite values are tracked with a running sum and Welford's
+/// add/remove updates, non-finite ones are counted separately, so they do not affect the
+/// results once left the window
+#[derive(Default)]
+struct RollingState {
+    finite: usize,
+    sum: f64,
+    mean: f64,
+    m2: f64,
+    nan: usize,
+    pos_inf: usize,
+    neg_inf: usize,
+}
+
+impl RollingState {
+    #[allow(clippy::cast_precision_loss)]
+    fn push(&mut self, v: f64) {
+        if v.is_nan() {
+            self.nan += 1;
+        } else if v == f64::INFINITY {
+            self.pos_inf += 1;
+        } else if v == f64::NEG_INFINITY {
+            self.neg_inf += 1;
+        } else {
+            self.finite += 1;
+            self.sum += v;
+            let delta = v - self.mean;
+            self.mean += delta / self.finite as f64;
+            self.m2 += delta * (v - self.mean);
+        }
+    }
+    #[allow(clippy::cast_precision_loss)]
+    fn remove(&mut self, v: f64) {
+        if v.is_nan() {
+            self.nan -= 1;
+        } else if v == f64::INFINITY {
+            self.pos_inf -= 1;
+        } else if v == f64::NEG_INFINITY {
+            self.neg_inf -= 1;
+        } else {
+            self.finite -= 1;
+            if self.finite == 0 {
+                self.sum = 0.0;
+                self.mean = 0.0;
+                self.m2 = 0.0;
+            } else {
+                self.sum -= v;
+                let delta = v - self.mean;
+                self.mean -= delta / self.finite as f64;
+                self.m2 = (self.m2 - delta * (v - self.mean)).max(0.0);
+            }
+        }
+    }
+    /// Number of non-null values in the window
+    fn count(&self) -> usize {
+        self.finite + self.nan + self.pos_inf + self.neg_inf
+    }
+    /// Sum of the window values, NaN if there are NaNs or infinities of both signs
+    fn sum(&self) -> f64 {
+        if self.nan > 0 || (self.pos_inf > 0 && self.neg_inf > 0) {
+            f64::NAN
+        } else if self.pos_inf > 0 {
+            f64::INFINITY
+        } else if self.neg_inf > 0 {
+            f64::NEG_INFINITY
+        } else {
+            self.sum
+        }
+    }
+    /// Sample variance of the window values, NaN if there are non-finite values
+    #[allow(clippy::cast_precision_loss)]
+    fn sample_var(&self) -> f64 {
+        if self.count() > self.finite {
+            f64::NAN
+        } else {
+            self.m2 / (self.finite - 1) as f64
+        }
+    }
+}
+
+/// Sliding window statistics, calls func for each position
+fn rolling_stats<F>(values: &Float64Array, window: usize, min_periods: usize, func: F) -> Series
+where
+    F: Fn(&RollingState) -> Option<f64>,
+{
+    let mut state = RollingState::default();
+    let mut result: Vec<Option<f64>> = Vec::with_capacity(values.len());
+    for i in 0..values.len() {
+        if let Some(v) = values.get(i) {
+            state.push(v);
+        }
+        if i >= window {
+            if let Some(v) = values.get(i - window) {
+                state.remove(v);
+            }
+        }
+        let count = state.count();
+        result.push(if count > 0 && count >= min_periods {
+            func(&state)
+        } else {
+            None
+        });
+    }
+    Float64Array::from(result).boxed()
+}
+
+/// Sliding window minimum (max = false) or maximum (max = true), monotonic deque
+///
+/// NaNs are not put into the deque, the result is NaN while there is a NaN in the window
+fn rolling_extremum(values: &Float64Array, window: usize, min_periods: usize, max: bool) -> Series {
+    let mut deque: VecDeque<(usize, f64)> = VecDeque::new();
+    let mut count = 0;
+    let mut nan = 0;
+    let mut result: Vec<Option<f64>> = Vec::with_capacity(values.len());
+    for i in 0..values.len() {
+        if let Some(v) = values.get(i) {
+            count += 1;
+            if v.is_nan() {
+                nan += 1;
+            } else {
+                while let Some((_, last)) = deque.back() {
+                    if (max && *last <= v) || (!max && *last >= v) {
+                        deque.pop_back();
+                    } else {
+                        break;
+                    }
+                }
+                deque.push_back((i, v));
+            }
+        }
+        if i >= window {
+            if let Some(v) = values.get(i - window) {
+                count -= 1;
+                if v.is_nan() {
+                    nan -= 1;
+                }
+            }
+            while let Some((pos, _)) = deque.front() {
+                if *pos + window <= i {
+                    deque.pop_front();
+                } else {
+                    break;
+                }
+            }
+        }
+        result.push(if count > 0 && count >= min_periods {
+            if nan > 0 {
+                Some(f64::NAN)
+            } else {
+                deque.front().map(|(_, v)| *v)
+            }
+        } else {
+            None
+        });
+    }
+    Float64Array::from(result).boxed()
+}
+
+fn cumulate<T, F>(values: &PrimitiveArray<T>, skipna: bool, op: F) -> Result<Series, Error>
+where
+    T: NativeType,
+    F: Fn(T, T) -> Option<T>,
+{
+    let mut acc: Option<T> = None;
+    let mut broken = false;
+    let mut result: Vec<Option<T>> = Vec::with_capacity(values.len());
+    for value in values {
+        match value {
+            Some(x) if !broken => {
+                acc = Some(if let Some(a) = acc {
+                    op(a, *x).ok_or(Error::Overflow)?
+                } else {
+                    *x
+                });
+                result.push(acc);
+            }
+            Some(_) => result.push(None),
+            None => {
+                if skipna {
+                    result.push(acc);
+                } else {
+                    broken = true;
+                    result.push(None);
+                }
+            }
+        }
+    }
+    Ok(PrimitiveArray::<T>::from(result).boxed())
+}
+
+/// Calls func(value, value periods before) for each position, the first periods (the last for
+/// negative periods) positions are nulls
+fn diff_with<T, O, F>(
+    values: &PrimitiveArray<T>,
+    periods: i64,
+    func: F,
+) -> Result<Vec<Option<O>>, Error>
+where
+    T: NativeType,
+    F: Fn(T, T) -> Result<Option<O>, Error>,
+{
+    let len = values.len();
+    let shift = usize::try_from(periods.unsigned_abs()).unwrap_or(usize::MAX);
+    if shift >= len {
+        return Err(Error::InvalidArgument(format!(
+            "periods ({}) must be less than the number of rows ({})",
+            periods, len
+        )));
+    }
+    let mut result: Vec<Option<O>> = Vec::with_capacity(len);
+    for i in 0..len {
+        let prev = if periods >= 0 {
+            i.checked_sub(shift)
+        } else {
+            Some(i + shift).filter(|p| *p < len)
+        };
+        result.push(
+            if let (Some(x), Some(y)) = (values.get(i), prev.and_then(|p| values.get(p))) {
+                func(x, y)?
+            } else {
+                None
+            },
+        );
+    }
+    Ok(result)
+}
+
+impl DataFrame {
+    /// Rolling mean of a Float64 column
+    ///
+    /// Positions with less than min_periods non-null values in the window are nulls,
+    /// NaN and infinite values affect only the windows which contain them
+    #[allow(clippy::cast_precision_loss)]
+    pub fn rolling_mean(
+        &self,
+        name: &str,
+        window: usize,
+        min_periods: usize,
+    ) -> Result<Series, Error> {
+        check_window(window, min_periods)?;
+        Ok(rolling_stats(
+            self.get_float64(name)?,
+            window,
+            min_periods,
+            |state| Some(state.sum() / state.count() as f64),
+        ))
+    }
+    /// Rolling sum of a Float64 column
+    ///
+    /// Positions with less than min_periods non-null values in the window are nulls,
+    /// NaN and infinite values affect only the windows which contain them
+    pub fn rolling_sum(
+        &self,
+        name: &str,
+        window: usize,
+        min_periods: usize,
+    ) -> Result<Series, Error> {
+        check_window(window, min_periods)?;
+        Ok(rolling_stats(
+            self.get_float64(name)?,
+            window,
+            min_periods,
+            |state| Some(state.sum()),
+        ))
+    }
+    /// Rolling minimum of a Float64 column
+    ///
+    /// Positions with less than min_periods non-null values in the window are nulls,
+    /// windows with NaN values give NaN
+    pub fn rolling_min(
+        &self,
+        name: &str,
+        window: usize,
+        min_periods: usize,
+    ) -> Result<Series, Error> {
+        check_window(window, min_periods)?;
+        Ok(rolling_extremum(
+            self.get_float64(name)?,
+            window,
+            min_periods,
+            false,
+        ))
+    }
+    /// Rolling maximum of a Float64 column
+    ///
+    /// Positions with less than min_periods non-null values in the window are nulls,
+    /// windows with NaN values give NaN
+    pub fn rolling_max(
+        &self,
+        name: &str,
+        window: usize,
+        min_periods: usize,
+    ) -> Result<Series, Error> {
+        check_window(window, min_periods)?;
+        Ok(rolling_extremum(
+            self.get_float64(name)?,
+            window,
+            min_periods,
+            true,
+        ))
+    }
+    /// Rolling sample standard deviation (ddof = 1) of a Float64 column
+    ///
+    /// Positions with less than min_periods (or less than 2) non-null values in the window are
+    /// nulls, windows with NaN or infinite values give NaN
+    pub fn rolling_std(
+        &self,
+        name: &str,
+        window: usize,
+        min_periods: usize,
+    ) -> Result<Series, Error> {
+        check_window(window, min_periods)?;
+        Ok(rolling_stats(
+            self.get_float64(name)?,
+            window,
+            min_periods,
+            |state| {
+                if state.count() > 1 {
+                    Some(state.sample_var().sqrt())
+                } else {
+                    None
+                }
+            },
+        ))
+    }
+    /// Exponentially weighted mean of a Float64 column: y[i] = alpha * x[i] + (1 - alpha) *
+    /// y[i-1], y[0] = x[0]
+    ///
+    /// Either span (alpha = 2 / (span + 1)) or alpha must be specified. Nulls are skipped, the
+    /// previous value is kept in their positions
+    pub fn ewm_mean(
+        &self,
+        name: &str,
+        span: Option<f64>,
+        alpha: Option<f64>,
+    ) -> Result<Series, Error> {
+        let alpha = match (span, alpha) {
+            (Some(span), None) if span >= 1.0 => 2.0 / (span + 1.0),
+            (None, Some(alpha)) if alpha > 0.0 && alpha <= 1.0 => alpha,
+            (Some(_), None) => {
+                return Err(Error::InvalidArgument("span must be >= 1".to_owned()));
+            }
+            (None, Some(_)) => {
+                return Err(Error::InvalidArgument("alpha must be in (0, 1]".to_owned()));
+            }
+            _ => {
+                return Err(Error::InvalidArgument(
+                    "either span or alpha must be specified".to_owned(),
+                ));
+            }
+        };
+        let mut current: Option<f64> = None;
+        let result: Float64Array = self
+            .get_float64(name)?
+            .iter()
+            .map(|v| {
+                if let Some(x) = v {
+                    current = Some(current.map_or(*x, |y| alpha * x + (1.0 - alpha) * y));
+                }
+                current
+            })
+            .collect();
+        Ok(result.boxed())
+    }
+    /// Cumulative sum of a Float64 or Int64 column
+    ///
+    /// If skipna is false, a null stops the cumulation and the rest values are nulls, otherwise
+    /// nulls are skipped and the previous result is kept in their positions. Returns
+    /// [`Error::Overflow`] if an Int64 result does not fit into i64
+    #[inline]
+    pub fn cumsum(&self, name: &str, skipna: bool) -> Result<Series, Error> {
+        self.cumulative(name, skipna, |a, b| Some(a + b), i64::checked_add)
+    }
+    /// Cumulative product of a Float64 or Int64 column
+    ///
+    /// See [`DataFrame::cumsum`] for the null handling
+    #[inline]
+    pub fn cumprod(&self, name: &str, skipna: bool) -> Result<Series, Error> {
+        self.cumulative(name, skipna, |a, b| Some(a * b), i64::checked_mul)
+    }
+    /// Cumulative maximum of a Float64 or Int64 column
+    ///
+    /// See [`DataFrame::cumsum`] for the null handling
+    #[inline]
+    pub fn cummax(&self, name: &str, skipna: bool) -> Result<Series, Error> {
+        self.cumulative(name, skipna, |a, b| Some(a.max(b)), |a, b| Some(a.max(b)))
+    }
+    /// Cumulative minimum of a Float64 or Int64 column
+    ///
+    /// See [`DataFrame::cumsum`] for the null handling
+    #[inline]
+    pub fn cummin(&self, name: &str, skipna: bool) -> Result<Series, Error> {
+        self.cumulative(name, skipna, |a, b| Some(a.min(b)), |a, b| Some(a.min(b)))
+    }
+    fn cumulative(
+        &self,
+        name: &str,
+        skipna: bool,
+        op_float: fn(f64, f64) -> Option<f64>,
+        op_int: fn(i64, i64) -> Option<i64>,
+    ) -> Result<Series, Error> {
+        let (_, data_type) = self
+            .get_series(name)
+            .ok_or_else(|| Error::NotFound(name.to_owned()))?;
+        match data_type {
+            DataType::Float64 => cumulate(self.get_float64(name)?, skipna, op_float),
+            DataType::Int64 => cumulate(self.get_int64(name)?, skipna, op_int),
+            _ => Err(Error::TypeMismatch),
+        }
+    }
+    /// Differences of a Float64 or Int64 column values: out[i] = col[i] - col[i - periods]
+    ///
+    /// The first periods values (the last for negative periods) are nulls. Returns
+    /// [`Error::Overflow`] if an Int64 result does not fit into i64
+    pub fn diff_column(&self, name: &str, periods: i64) -> Result<Series, Error> {
+        let (_, data_type) = self
+            .get_series(name)
+            .ok_or_else(|| Error::NotFound(name.to_owned()))?;
+        match data_type {
+            DataType::Float64 => Ok(Float64Array::from(diff_with(
+                self.get_float64(name)?,
+                periods,
+                |x, y| Ok(Some(x - y)),
+            )?)
+            .boxed()),
+            DataType::Int64 => Ok(Int64Array::from(diff_with(
+                self.get_int64(name)?,
+                periods,
+                |x, y| x.checked_sub(y).map(Some).ok_or(Error::Overflow),
+            )?)
+            .boxed()),
+            _ => Err(Error::TypeMismatch),
+        }
+    }
+    /// Percentage change of a Float64 column values: (x[i] - x[i - periods]) / x[i - periods]
+    ///
+    /// The first periods values and divisions by zero are nulls. Returns
+    /// [`Error::InvalidArgument`] if periods is not less than the number of rows
+    pub fn pct_change(&self, name: &str, periods: usize) -> Result<Series, Error> {
+        let periods = i64::try_from(periods)
+            .map_err(|_| Error::InvalidArgument(format!("periods is too large: {}", periods)))?;
+        Ok(
+            Float64Array::from(diff_with(self.get_float64(name)?, periods, |x, y| {
+                Ok(if y == 0.0 { None } else { Some((x - y) / y) })
+            })?)
+            .boxed(),
+        )
+    }
+    /// Shift a column values by periods positions, positive periods move values toward higher
+    /// indices
+    ///
+    /// The freed positions are filled with nulls, the column type is kept
+    pub fn shift(&self, name: &str, periods: i64) -> Result<Series, Error> {
+        let (serie, _) = self
+            .get_series(name)
+            .ok_or_else(|| Error::NotFound(name.to_owned()))?;
+        let len = serie.len();
+        let shift = usize::try_from(periods.unsigned_abs()).unwrap_or(usize::MAX);
+        if shift >= len {
+            return Ok(new_null_array(serie.data_type().clone(), len));
+        }
+        if shift == 0 {
+            return Ok(serie.clone());
+        }
+        let nulls = new_null_array(serie.data_type().clone(), shift);
+        let result = if periods > 0 {
+            concatenate(&[nulls.as_ref(), serie.sliced(0, len - shift).as_ref()])?
+        } else {
+            concatenate(&[serie.sliced(shift, len - shift).as_ref(), nulls.as_ref()])?
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn float_df(values: Vec<Option<f64>>) -> DataFrame {
+        let mut df = DataFrame::new0();
+        df.add_series0("x", Float64Array::from(values).boxed())
+            .unwrap();
+        df
+    }
+
+    /// Compare the series values with expected ones, NaNs are equal to each other
+    fn assert_values(serie: &Series, expected: &[Option<f64>]) {
+        let values: &Float64Array = serie.as_any().downcast_ref().unwrap();
+        assert_eq!(values.len(), expected.len());
+        for (i, (v, e)) in values.iter().zip(expected).enumerate() {
+            match (v, e) {
+                (Some(v), Some(e)) if v.is_nan() || e.is_nan() => {
+                    assert!(v.is_nan() && e.is_nan(), "{}: {} != {}", i, v, e);
+                }
+                (Some(v), Some(e)) if v.is_infinite() || e.is_infinite() => {
+                    assert_eq!(v, e, "{}", i);
+                }
+                (Some(v), Some(e)) => assert!((v - e).abs() < 1e-9, "{}: {} != {}", i, v, e),
+                (v, e) => assert_eq!(v.copied(), *e, "{}", i),
+            }
+        }
+    }
+
+    #[test]
+    fn test_rolling_mean() {
+        let df = float_df(vec![Some(1.0), Some(2.0), Some(3.0), Some(4.0), Some(5.0)]);
+        assert_values(
+            &df.rolling_mean("x", 3, 1).unwrap(),
+            &[Some(1.0), Some(1.5), Some(2.0), Some(3.0), Some(4.0)],
+        );
+        assert_values(
+            &df.rolling_mean("x", 3, 3).unwrap(),
+            &[None, None, Some(2.0), Some(3.0), Some(4.0)],
+        );
+        assert_values(
+            &df.rolling_mean("x", 10, 0).unwrap(),
+            &[Some(1.0), Some(1.5), Some(2.0), Some(2.5), Some(3.0)],
+        );
+        assert_values(
+            &df.rolling_mean("x", 1, 1).unwrap(),
+            &[Some(1.0), Some(2.0), Some(3.0), Some(4.0), Some(5.0)],
+        );
+        let df = float_df(vec![Some(1.0), None, Some(3.0), Some(4.0), None, None]);
+        assert_values(
+            &df.rolling_mean("x", 2, 1).unwrap(),
+            &[Some(1.0), Some(1.0), Some(3.0), Some(3.5), Some(4.0), None],
+        );
+        assert_values(
+            &df.rolling_mean("x", 2, 2).unwrap(),
+            &[None, None, None, Some(3.5), None, None],
+        );
+        assert!(matches!(
+            df.rolling_mean("x", 2, 3),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            df.rolling_mean("x", 0, 0),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert_eq!(float_df(vec![]).rolling_mean("x", 2, 1).unwrap().len(), 0);
+    }
+    #[test]
+    fn test_rolling_non_finite() {
+        let df = float_df(vec![
+            Some(1.0),
+            Some(f64::NAN),
+            Some(2.0),
+            Some(3.0),
+            Some(f64::INFINITY),
+            Some(f64::NEG_INFINITY),
+            Some(4.0),
+            Some(6.0),
+        ]);
+        assert_values(
+            &df.rolling_mean("x", 2, 1).unwrap(),
+            &[
+                Some(1.0),
+                Some(f64::NAN),
+                Some(f64::NAN),
+                Some(2.5),
+                Some(f64::INFINITY),
+                Some(f64::NAN),
+                Some(f64::NEG_INFINITY),
+                Some(5.0),
+            ],
+        );
+        assert_values(
+            &df.rolling_sum("x", 2, 1).unwrap(),
+            &[
+                Some(1.0),
+                Some(f64::NAN),
+                Some(f64::NAN),
+                Some(5.0),
+                Some(f64::INFINITY),
+                Some(f64::NAN),
+                Some(f64::NEG_INFINITY),
+                Some(10.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_rolling_stats() {
+        let df = float_df(vec![
+            Some(2.0),
+            Some(4.0),
+            Some(9.0),
+            None,
+            Some(1.0),
+            Some(7.0),
+        ]);
+        // windows: [2], [2, 4], [2, 4, 9], [4, 9, -], [9, -, 1], [-, 1, 7]
+        assert_values(
+            &df.rolling_mean("x", 3, 1).unwrap(),
+            &[
+                Some(2.0),
+                Some(3.0),
+                Some(5.0),
+                Some(6.5),
+                Some(5.0),
+                Some(4.0),
+            ],
+        );
+        assert_values(
+            &df.rolling_sum("x", 3, 2).unwrap(),
+            &[
+                None,
+                Some(6.0),
+                Some(15.0),
+                Some(13.0),
+                Some(10.0),
+                Some(8.0),
+            ],
+        );
+        assert_values(
+            &df.rolling_min("x", 3, 1).unwrap(),
+            &[
+                Some(2.0),
+                Some(2.0),
+                Some(2.0),
+                Some(4.0),
+                Some(1.0),
+                Some(1.0),
+            ],
+        );
+        assert_values(
+            &df.rolling_max("x", 3, 1).unwrap(),
+            &[
+                Some(2.0),
+                Some(4.0),
+                Some(9.0),
+                Some(9.0),
+                Some(9.0),
+                Some(7.0),
+            ],
+        );
+        assert_values(
+            &df.rolling_max("x", 3, 3).unwrap(),
+            &[None, None, Some(9.0), None, None, None],
+        );
+        assert_values(
+            &df.rolling_std("x", 3, 1).unwrap(),
+            &[
+                None,
+                Some(2.0_f64.sqrt()),
+                Some(13.0_f64.sqrt()),
+                Some(12.5_f64.sqrt()),
+                Some(32.0_f64.sqrt()),
+                Some(18.0_f64.sqrt()),
+            ],
+        );
+        assert!(matches!(
+            df.rolling_std("x", 3, 4),
+            Err(Error::InvalidArgument(_))
+        ));
+        let df = float_df(vec![Some(1.0), Some(2.0), Some(3.0)]);
+        assert_values(&df.rolling_std("x", 1, 1).unwrap(), &[None, None, None]);
+    }
+    #[test]
+    fn test_rolling_std_large_values() {
+        let offset = 1e9;
+        let df = float_df(
+            [4.0, 7.0, 13.0, 16.0, 4.0, 7.0, 13.0, 16.0]
+                .iter()
+                .map(|v| Some(v + offset))
+                .collect(),
+        );
+        let result = df.rolling_std("x", 4, 4).unwrap();
+        let values: &Float64Array = result.as_any().downcast_ref().unwrap();
+        for i in 3..8 {
+            assert!((values.value(i) - 30.0_f64.sqrt()).abs() < 1e-6);
+        }
+    }
+    #[test]
+    fn test_rolling_extremum_nan() {
+        let df = float_df(vec![
+            Some(3.0),
+            Some(f64::NAN),
+            Some(1.0),
+            Some(2.0),
+            None,
+            Some(f64::NAN),
+        ]);
+        let expected_min = [
+            Some(3.0),
+            Some(f64::NAN),
+            Some(f64::NAN),
+            Some(1.0),
+            Some(2.0),
+            Some(f64::NAN),
+        ];
+        assert_values(&df.rolling_min("x", 2, 1).unwrap(), &expected_min);
+        assert_values(
+            &df.rolling_max("x", 2, 1).unwrap(),
+            &[
+                Some(3.0),
+                Some(f64::NAN),
+                Some(f64::NAN),
+                Some(2.0),
+                Some(2.0),
+                Some(f64::NAN),
+            ],
+        );
+        assert_values(
+            &df.rolling_std("x", 2, 1).unwrap(),
+            &[
+                None,
+                Some(f64::NAN),
+                Some(f64::NAN),
+                Some(0.5_f64.sqrt()),
+                None,
+                None,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_ewm_mean() {
+        // pandas: Series([1.0, 2.0, 3.0]).ewm(alpha=0.5, adjust=False).mean()
+        let df = float_df(vec![Some(1.0), Some(2.0), Some(3.0)]);
+        let expected = [Some(1.0), Some(1.5), Some(2.25)];
+        assert_values(&df.ewm_mean("x", None, Some(0.5)).unwrap(), &expected);
+        assert_values(&df.ewm_mean("x", Some(3.0), None).unwrap(), &expected);
+        // pandas: Series([1.0, 2.0, 3.0]).ewm(span=2, adjust=False).mean()
+        assert_values(
+            &df.ewm_mean("x", Some(2.0), None).unwrap(),
+            &[Some(1.0), Some(5.0 / 3.0), Some(23.0 / 9.0)],
+        );
+        let df = float_df(vec![None, Some(1.0), None, Some(3.0)]);
+        assert_values(
+            &df.ewm_mean("x", None, Some(0.5)).unwrap(),
+            &[None, Some(1.0), Some(1.0), Some(2.0)],
+        );
+        for (span, alpha) in [
+            (None, None),
+            (Some(3.0), Some(0.5)),
+            (Some(0.5), None),
+            (None, Some(0.0)),
+            (None, Some(1.5)),
+        ] {
+            assert!(matches!(
+                df.ewm_mean("x", span, alpha),
+                Err(Error::InvalidArgument(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_cumulative() {
+        let df = float_df(vec![Some(1.0), None, Some(2.0), Some(3.0)]);
+        assert_values(
+            &df.cumsum("x", false).unwrap(),
+            &[Some(1.0), None, None, None],
+        );
+        assert_values(
+            &df.cumsum("x", true).unwrap(),
+            &[Some(1.0), Some(1.0), Some(3.0), Some(6.0)],
+        );
+        assert_values(
+            &df.cumprod("x", true).unwrap(),
+            &[Some(1.0), Some(1.0), Some(2.0), Some(6.0)],
+        );
+        let df = float_df(vec![None, Some(2.0), Some(1.0), Some(3.0)]);
+        assert_values(
+            &df.cummax("x", true).unwrap(),
+            &[None, Some(2.0), Some(2.0), Some(3.0)],
+        );
+        assert_values(
+            &df.cummin("x", true).unwrap(),
+            &[None, Some(2.0), Some(1.0), Some(1.0)],
+        );
+        assert_values(&df.cummin("x", false).unwrap(), &[None; 4]);
+        let mut df = DataFrame::new0();
+        df.add_series0(
+            "i",
+            Int64Array::from(vec![Some(3), None, Some(-4), Some(5)]).boxed(),
+        )
+        .unwrap();
+        let result = df.cumsum("i", true).unwrap();
+        assert_eq!(result.data_type(), &DataType::Int64);
+        let values: &Int64Array = result.as_any().downcast_ref().unwrap();
+        assert_eq!(
+            values.iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(3), Some(3), Some(-1), Some(4)]
+        );
+        let result = df.cummax("i", false).unwrap();
+        let values: &Int64Array = result.as_any().downcast_ref().unwrap();
+        assert_eq!(
+            values.iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(3), None, None, None]
+        );
+        df.add_series0("j", Int64Array::from_vec(vec![i64::MAX, 1, 0, 0]).boxed())
+            .unwrap();
+        assert!(matches!(df.cumsum("j", true), Err(Error::Overflow)));
+        df.add_series0(
+            "b",
+            arrow2::array::BooleanArray::from_slice([true; 4]).boxed(),
+        )
+        .unwrap();
+        assert!(matches!(df.cumsum("b", true), Err(Error::TypeMismatch)));
+    }
+
+    #[test]
+    fn test_diff_column() {
+        let df = float_df(vec![Some(1.0), Some(4.0), None, Some(10.0), Some(20.0)]);
+        assert_values(
+            &df.diff_column("x", 1).unwrap(),
+            &[None, Some(3.0), None, None, Some(10.0)],
+        );
+        assert_values(
+            &df.diff_column("x", 2).unwrap(),
+            &[None, None, None, Some(6.0), None],
+        );
+        assert_values(
+            &df.diff_column("x", -1).unwrap(),
+            &[Some(-3.0), None, None, Some(-10.0), None],
+        );
+        assert_values(
+            &df.diff_column("x", 0).unwrap(),
+            &[Some(0.0), Some(0.0), None, Some(0.0), Some(0.0)],
+        );
+        assert!(matches!(
+            df.diff_column("x", 5),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            df.diff_column("x", -5),
+            Err(Error::InvalidArgument(_))
+        ));
+        let empty = float_df(vec![]);
+        assert!(matches!(
+            empty.diff_column("x", 0),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            empty.pct_change("x", 1),
+            Err(Error::InvalidArgument(_))
+        ));
+        let mut df = DataFrame::new0();
+        df.add_series0(
+            "i",
+            Int64Array::from(vec![Some(5), Some(2), None, Some(7)]).boxed(),
+        )
+        .unwrap();
+        let result = df.diff_column("i", -1).unwrap();
+        let values: &Int64Array = result.as_any().downcast_ref().unwrap();
+        assert_eq!(
+            values.iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(3), None, None, None]
+        );
+        df.add_series0("j", Int64Array::from_vec(vec![1, i64::MIN, 0, 0]).boxed())
+            .unwrap();
+        assert!(matches!(df.diff_column("j", -1), Err(Error::Overflow)));
+    }
+
+    #[test]
+    fn test_pct_change() {
+        let df = float_df(vec![Some(1.0), Some(2.0), Some(0.0), Some(3.0), Some(6.0)]);
+        assert_values(
+            &df.pct_change("x", 1).unwrap(),
+            &[None, Some(1.0), Some(-1.0), None, Some(1.0)],
+        );
+        assert_values(
+            &df.pct_change("x", 2).unwrap(),
+            &[None, None, Some(-1.0), Some(0.5), None],
+        );
+        assert!(matches!(
+            df.pct_change("x", 5),
+            Err(Error::InvalidArgument(_))
+        ));
+        let mut df = DataFrame::new0();
+        df.add_series0("i", Int64Array::from_vec(vec![1, 2]).boxed())
+            .unwrap();
+        assert!(matches!(df.pct_change("i", 1), Err(Error::TypeMismatch)));
+    }
+
+    #[test]
+    fn test_shift() {
+        let values = vec![Some(1.0), Some(2.0), None, Some(4.0), Some(5.0)];
+        let df = float_df(values.clone());
+        assert_values(
+            &df.shift("x", 2).unwrap(),
+            &[None, None, Some(1.0), Some(2.0), None],
+        );
+        assert_values(
+            &df.shift("x", -1).unwrap(),
+            &[Some(2.0), None, Some(4.0), Some(5.0), None],
+        );
+        assert_values(&df.shift("x", 0).unwrap(), &values);
+        assert_values(&df.shift("x", 5).unwrap(), &[None; 5]);
+        assert_values(&df.shift("x", -7).unwrap(), &[None; 5]);
+        for k in 1..3 {
+            let mut shifted = DataFrame::new0();
+            shifted.add_series0("x", df.shift("x", k).unwrap()).unwrap();
+            let back = shifted.shift("x", -k).unwrap();
+            let len = values.len() - usize::try_from(k).unwrap();
+            assert_values(&back.sliced(0, len), &values[..len]);
+        }
+        let mut df = DataFrame::new0();
+        df.add_series0(
+            "s",
+            arrow2::array::Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+        )
+        .unwrap();
+        let shifted = df.shift("s", 1).unwrap();
+        assert_eq!(shifted.data_type(), &DataType::Utf8);
+        assert!(shifted.is_null(0));
+        assert!(matches!(df.shift("x", 1), Err(Error::NotFound(_))));
+    }
+}